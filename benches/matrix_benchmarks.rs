@@ -1,17 +1,79 @@
 #[macro_use]
 extern crate criterion;
 
+use std::hint::black_box;
+
 use criterion::Criterion;
 
 use straal::*;
+use straal::simd::scalar;
+
+fn get_rigid_mat() -> Mat4n {
+    let mut m = Mat4n::get_translation_mat(Vec3n::new(1.0, -2.0, 3.0));
+    m.rotate_around_axis_deg(Vec3n::new(1.0, 2.0, 3.0).normalized(), 40.0);
+    m
+}
+
+fn get_trs_mat() -> Mat4n {
+    let mut m = get_rigid_mat();
+    m.scale_uniformly(Vec3n::new(2.0, 0.5, 3.0));
+    m
+}
 
 fn mat3_mul(c: &mut Criterion) {
-    c.bench_function("mat3 multiplication", |b| b.iter(|| Mat3::identity() * Mat3::identity()));
+    c.bench_function("mat3 multiplication", |b| b.iter(|| black_box(Mat3n::identity()) * black_box(Mat3n::identity())));
+}
+
+fn euler_angles_bench(c: &mut Criterion) {
+    c.bench_function("mat3 from euler angles obj upr", |b| b.iter(|| Mat3n::get_rotation_mat_euler_obj_upr_deg(black_box(89.0), black_box(89.0), black_box(89.0))));
+}
+
+fn mat3_inverse_bench(c: &mut Criterion) {
+    let m = Mat3n::get_angle_axis_mat_deg(Vec3n::new(1.0, 2.0, 3.0).normalized(), 40.0);
+    c.bench_function("mat3 inverse", |b| b.iter(|| black_box(m).inverse()));
+    c.bench_function("mat3 inverse orthonormal", |b| b.iter(|| black_box(m).inverse_orthonormal()));
+}
+
+fn mat4_inverse_bench(c: &mut Criterion) {
+    let trs = get_trs_mat();
+    let rigid = get_rigid_mat();
+    c.bench_function("mat4 inverse", |b| b.iter(|| black_box(trs).inverse()));
+    c.bench_function("mat4 try inverse", |b| b.iter(|| black_box(trs).try_inverse()));
+    c.bench_function("mat4 inverse affine", |b| b.iter(|| black_box(trs).inverse_affine()));
+    c.bench_function("mat4 inverse rigid", |b| b.iter(|| black_box(rigid).inverse_rigid()));
+}
+
+//Mat4n and Quatn go through SIMD where the target supports it, scalar:: is the generic fallback they replace
+fn simd_bench(c: &mut Criterion) {
+    let trs = get_trs_mat();
+    let rigid = get_rigid_mat();
+    let v = Vec4n::new(1.0, 2.0, 3.0, 1.0);
+    let q = Quatn::get_quat_from_angle_axis(0.7, Vec3n::new(1.0, 2.0, 3.0).normalized());
+    c.bench_function("mat4 multiplication", |b| b.iter(|| black_box(trs) * black_box(rigid)));
+    c.bench_function("mat4 multiplication scalar", |b| b.iter(|| scalar::mat4_mul(&black_box(trs), &black_box(rigid))));
+    c.bench_function("mat4 vec4 multiplication", |b| b.iter(|| black_box(trs) * black_box(v)));
+    c.bench_function("mat4 vec4 multiplication scalar", |b| b.iter(|| scalar::mat4_mul_vec4(&black_box(trs), black_box(v))));
+    c.bench_function("mat4 transpose", |b| b.iter(|| black_box(trs).transpose()));
+    c.bench_function("mat4 transpose scalar", |b| b.iter(|| scalar::mat4_transpose(&black_box(trs))));
+    c.bench_function("mat4 inverse scalar", |b| b.iter(|| scalar::mat4_inverse(&black_box(trs))));
+    c.bench_function("quat multiplication", |b| b.iter(|| black_box(q) * black_box(q)));
+    c.bench_function("quat multiplication scalar", |b| b.iter(|| scalar::quat_mul(black_box(q), black_box(q))));
 }
 
-fn zxy_angle_axes_bench(c: &mut Criterion) {
-    c.bench_function("optimized mat3 angles to axes zxy", |b| b.iter(|| Mat3::angles_to_axes_zxy(Vec3::new(89.0, 89.0, 89.0))));
+fn bulk_transform_bench(c: &mut Criterion) {
+    let trs = get_trs_mat();
+    let q = Quatn::get_quat_from_angle_axis(0.7, Vec3n::new(1.0, 2.0, 3.0).normalized());
+    let points: Vec<Vec3n> = (0..1000).map(|i| Vec3n::new(i as f32, 1.0, -(i as f32))).collect();
+    let mut out = vec![Vec3n::zero(); points.len()];
+    c.bench_function("mat4 transform 1000 points", |b| b.iter(|| black_box(trs).transform_points(black_box(&points), &mut out)));
+    c.bench_function("mat4 transform 1000 normals", |b| b.iter(|| black_box(trs).transform_normals(black_box(&points), &mut out)));
+    c.bench_function("quat rotate 1000 vectors", |b| b.iter(|| black_box(q).transform_vectors(black_box(&points), &mut out)));
+    c.bench_function("quat rotate 1000 vectors one by one", |b| b.iter(|| {
+        for (p, o) in black_box(&points).iter().zip(out.iter_mut()) {
+            *o = black_box(q) * *p;
+        }
+    }));
 }
 
-criterion_group!(benches, zxy_angle_axes_bench);
-criterion_main!(benches);
\ No newline at end of file
+criterion_group!(benches, mat3_mul, euler_angles_bench, mat3_inverse_bench, mat4_inverse_bench, simd_bench, bulk_transform_bench);
+criterion_main!(benches);
@@ -0,0 +1,130 @@
+//ApproxEqual for the vectors, matrices, quaternions and complex numbers, they are only equal when every component is,
+//and the assert macros that go with them, which print both sides when they fail
+
+use super::*;
+
+macro_rules! impl_approx_eq {
+    ($ty:ident, [$($field:ident),+]) => {
+        impl<S> ApproxEqual<S> for $ty<S> where S: FloatType<S> {
+            fn approx_eq(self, rhs: $ty<S>, epsilon: S) -> bool {
+                $(self.$field.approx_eq(rhs.$field, epsilon))&&+
+            }
+        }
+
+        impl<S> ApproxEqualExt<S> for $ty<S> where S: FloatType<S> {
+            fn abs_diff_eq(self, rhs: $ty<S>, epsilon: S) -> bool {
+                $(self.$field.abs_diff_eq(rhs.$field, epsilon))&&+
+            }
+
+            fn relative_eq(self, rhs: $ty<S>, epsilon: S, max_relative: S) -> bool {
+                $(self.$field.relative_eq(rhs.$field, epsilon, max_relative))&&+
+            }
+
+            fn ulps_eq(self, rhs: $ty<S>, epsilon: S, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(rhs.$field, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+impl_approx_eq!(Vec2, [x, y]);
+impl_approx_eq!(Vec3, [x, y, z]);
+impl_approx_eq!(Vec4, [x, y, z, w]);
+impl_approx_eq!(Mat2, [r0, r1]);
+impl_approx_eq!(Mat3, [r0, r1, r2]);
+impl_approx_eq!(Mat4, [r0, r1, r2, r3]);
+impl_approx_eq!(Mat2x3, [r0, r1, r2]);
+impl_approx_eq!(Mat2x4, [r0, r1, r2, r3]);
+impl_approx_eq!(Mat3x2, [r0, r1]);
+impl_approx_eq!(Mat3x4, [r0, r1, r2, r3]);
+impl_approx_eq!(Mat4x2, [r0, r1]);
+impl_approx_eq!(Mat4x3, [r0, r1, r2]);
+impl_approx_eq!(Quat, [w, v]);
+impl_approx_eq!(Complex, [re, im]);
+
+//DEF_EPSILON for whatever S the value compares in, for the assert macros
+pub fn get_default_epsilon<T, S>(_value: &T) -> S where T: ApproxEqual<S>, S: FloatType<S> {
+    S::DEF_EPSILON
+}
+
+//assert_approx_eq!(left, right) or assert_approx_eq!(left, right, epsilon), with DEF_EPSILON when it is left out
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::assert_approx_eq!(*left, *right, $crate::approx::get_default_epsilon(left)),
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        match (&$left, &$right, $epsilon) {
+            (left, right, epsilon) => {
+                if !$crate::ApproxEqual::approx_eq(*left, *right, epsilon) {
+                    panic!("assertion failed: `left ≈ right` (epsilon: {:?})\n  left: `{:?}`\n right: `{:?}`", epsilon, left, right);
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::assert_abs_diff_eq!(*left, *right, $crate::approx::get_default_epsilon(left)),
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        match (&$left, &$right, $epsilon) {
+            (left, right, epsilon) => {
+                if !$crate::ApproxEqualExt::abs_diff_eq(*left, *right, epsilon) {
+                    panic!("assertion failed: `left ≈ right` (epsilon: {:?})\n  left: `{:?}`\n right: `{:?}`", epsilon, left, right);
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr, $epsilon:expr, $max_relative:expr) => {
+        match (&$left, &$right, $epsilon, $max_relative) {
+            (left, right, epsilon, max_relative) => {
+                if !$crate::ApproxEqualExt::relative_eq(*left, *right, epsilon, max_relative) {
+                    panic!("assertion failed: `left ≈ right` (epsilon: {:?}, max_relative: {:?})\n  left: `{:?}`\n right: `{:?}`", epsilon, max_relative, left, right);
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr, $epsilon:expr, $max_ulps:expr) => {
+        match (&$left, &$right, $epsilon, $max_ulps) {
+            (left, right, epsilon, max_ulps) => {
+                if !$crate::ApproxEqualExt::ulps_eq(*left, *right, epsilon, max_ulps) {
+                    panic!("assertion failed: `left ≈ right` (epsilon: {:?}, max_ulps: {:?})\n  left: `{:?}`\n right: `{:?}`", epsilon, max_ulps, left, right);
+                }
+            }
+        }
+    };
+}
+
+//For quaternions that should describe the same rotation, q and -q both pass
+#[macro_export]
+macro_rules! assert_rotation_approx_eq {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::assert_rotation_approx_eq!(*left, *right, $crate::approx::get_default_epsilon(left)),
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        match (&$left, &$right, $epsilon) {
+            (left, right, epsilon) => {
+                if !left.rotation_approx_eq(*right, epsilon) {
+                    panic!("assertion failed: `left ≈ right` as rotations (epsilon: {:?})\n  left: `{:?}`\n right: `{:?}`", epsilon, left, right);
+                }
+            }
+        }
+    };
+}
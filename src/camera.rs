@@ -0,0 +1,119 @@
+use super::*;
+
+//A camera defined by a position and an orientation, the orientation maps the local axes of the camera onto the world
+//(local x is right, local y is up and the view direction is local +z for left-handed cameras and local -z for right-handed ones)
+#[derive(Copy, Clone, Debug)]
+pub struct Camera<S> {
+    pub position: Vec3<S>,
+    pub orientation: Quat<S>,
+    pub projection: Mat4<S>,
+    pub hand: Handedness,
+}
+
+impl<S> Camera<S> where S: FloatType<S> {
+    pub fn new(position: Vec3<S>, orientation: Quat<S>, projection: Mat4<S>, hand: Handedness) -> Camera<S> {
+        Camera {
+            position,
+            orientation,
+            projection,
+            hand,
+        }
+    }
+
+    pub fn new_looking_at(position: Vec3<S>, target: Vec3<S>, up: Vec3<S>, projection: Mat4<S>, hand: Handedness) -> Camera<S> {
+        let mut camera = Camera::new(position, Quat::identity(), projection, hand);
+        camera.look_at(target, up);
+        camera
+    }
+
+    pub fn forward(&self) -> Vec3<S> {
+        self.orientation * (Vec3::forward() * self.hand.sign::<S>())
+    }
+
+    pub fn right(&self) -> Vec3<S> {
+        self.orientation * Vec3::right()
+    }
+
+    pub fn up(&self) -> Vec3<S> {
+        self.orientation * Vec3::up()
+    }
+
+    //Points the camera at the target, keeping the position as is
+    pub fn look_at(&mut self, target: Vec3<S>, up: Vec3<S>) {
+        let z_axis = (target - self.position).normalized() * self.hand.sign::<S>();
+        let x_axis = up.cross(z_axis).normalized();
+        let y_axis = z_axis.cross(x_axis);
+        self.orientation = Quat::from(Mat3::new_from_vec3s(x_axis, y_axis, z_axis)).normalized();
+    }
+
+    //First person style rotation, yaw turns around the world up axis (positive turns right) and pitch tilts the view
+    //direction up (positive) or down, pitch is clamped so the camera never flips over the poles
+    pub fn rotate_fps(&mut self, yaw: S, pitch: S) {
+        let rotation = self.get_yaw_pitch_rotation(yaw, pitch);
+        self.orientation = (rotation * self.orientation).normalized();
+    }
+
+    //Orbits the camera around the target, the yaw and pitch work the same way as rotate_fps,
+    //a camera that was looking at the target will keep looking at it
+    pub fn orbit(&mut self, target: Vec3<S>, yaw: S, pitch: S) {
+        let rotation = self.get_yaw_pitch_rotation(yaw, pitch);
+        self.position = target + rotation * (self.position - target);
+        self.orientation = (rotation * self.orientation).normalized();
+    }
+
+    //Arcball rotation around the target, from and to are cursor positions in normalized device coordinates ([-1, 1] on both axes),
+    //dragging the cursor spins the scene along with it, which moves the camera the opposite way around the target
+    pub fn arcball(&mut self, target: Vec3<S>, from: Vec2<S>, to: Vec2<S>) {
+        let p0 = self.get_arcball_point(from);
+        let p1 = self.get_arcball_point(to);
+        let arc = Quat { w: S::one() + p0.dot(p1), v: p0.cross(p1) };
+        if arc.magnitude_squared() < S::DEF_EPSILON {
+            return;
+        }
+
+        let scene_rotation = self.orientation * arc.normalized() * self.orientation.conjugate();
+        let rotation = scene_rotation.conjugate();
+        self.position = target + rotation * (self.position - target);
+        self.orientation = (rotation * self.orientation).normalized();
+    }
+
+    pub fn get_view_mat(&self) -> Mat4<S> {
+        Mat4::look_to(self.position, self.forward(), self.up(), self.hand)
+    }
+
+    pub fn get_view_projection_mat(&self) -> Mat4<S> {
+        self.projection * self.get_view_mat()
+    }
+
+    fn get_yaw_pitch_rotation(&self, yaw: S, pitch: S) -> Quat<S> {
+        let half_pi: S = num::cast(core::f64::consts::FRAC_PI_2).unwrap();
+        let max_pitch = half_pi - num::cast(0.001).unwrap();
+        let forward = self.forward();
+
+        let current_pitch = forward.y.max(-S::one()).min(S::one()).asin();
+        let new_pitch = (current_pitch + pitch).max(-max_pitch).min(max_pitch);
+
+        //Rotating around forward x up raises the view direction, the yaw axis flips with the handedness so positive yaw always turns right
+        let pitch_axis = forward.cross(Vec3::up());
+        let pitch_rot = if pitch_axis.length_squared() > S::DEF_EPSILON {
+            Quat::get_quat_from_angle_axis(new_pitch - current_pitch, pitch_axis.normalized())
+        } else {
+            Quat::identity()
+        };
+        let yaw_rot = Quat::get_quat_from_angle_axis(yaw, Vec3::up() * self.hand.sign::<S>());
+
+        yaw_rot * pitch_rot
+    }
+
+    //Maps a point in normalized device coordinates onto the arcball, in the local space of the camera
+    fn get_arcball_point(&self, point: Vec2<S>) -> Vec3<S> {
+        let dist_sq = point.length_squared();
+        let towards_viewer = -self.hand.sign::<S>();
+        if dist_sq <= S::one() {
+            Vec3 { x: point.x, y: point.y, z: (S::one() - dist_sq).sqrt() * towards_viewer }
+        } else {
+            let point = point.normalized();
+            Vec3 { x: point.x, y: point.y, z: S::zero() }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use core::fmt;
+
+use super::*;
+
+//The matrices that have a column-major form, Columns is how the columns get stored
+pub trait ColumnMajorLayout {
+    type Columns: Copy;
+}
+
+//A matrix stored column by column, which is the layout that OpenGL, Vulkan and most file formats expect,
+//it can only be made from (and turned back into) the regular row-major matrices, so the layout is always known,
+//the transpose happens once when converting, after that the data can be handed out as is
+#[repr(transparent)]
+pub struct ColumnMajor<M> where M: ColumnMajorLayout {
+    columns: M::Columns,
+}
+
+impl<M> Copy for ColumnMajor<M> where M: ColumnMajorLayout {}
+
+impl<M> Clone for ColumnMajor<M> where M: ColumnMajorLayout {
+    fn clone(&self) -> ColumnMajor<M> {
+        *self
+    }
+}
+
+impl<M> fmt::Debug for ColumnMajor<M> where M: ColumnMajorLayout, M::Columns: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ColumnMajor").field("columns", &self.columns).finish()
+    }
+}
+
+impl<M> PartialEq for ColumnMajor<M> where M: ColumnMajorLayout, M::Columns: PartialEq {
+    fn eq(&self, other: &ColumnMajor<M>) -> bool {
+        self.columns == other.columns
+    }
+}
+
+macro_rules! impl_column_major {
+    ($mat:ident, $vec_r:ident, $c:expr, $r:expr, $attribute_f32:ident, $attribute_f64:ident) => {
+        impl<S> ColumnMajorLayout for $mat<S> where S: FloatType<S> {
+            type Columns = [[S; $r]; $c];
+        }
+
+        impl<S> ColumnMajor<$mat<S>> where S: FloatType<S> {
+            pub fn to_row_major(&self) -> $mat<S> {
+                let mut m = $mat::empty();
+                for col in 0..$c {
+                    for row in 0..$r {
+                        m[row][col] = self.columns[col][row];
+                    }
+                }
+                m
+            }
+
+            pub fn get_column(&self, index: usize) -> $vec_r<S> {
+                $vec_r::from(self.columns[index])
+            }
+
+            //The columns, without copying anything
+            pub fn as_array(&self) -> &[[S; $r]; $c] {
+                &self.columns
+            }
+
+            //All elements, column after column, without copying anything
+            pub fn as_slice(&self) -> &[S] {
+                self.columns.as_flattened()
+            }
+        }
+
+        impl<S> $mat<S> where S: FloatType<S> {
+            pub fn to_column_major(&self) -> ColumnMajor<$mat<S>> {
+                let mut columns = [[S::zero(); $r]; $c];
+                for (col, column) in columns.iter_mut().enumerate() {
+                    for (row, element) in column.iter_mut().enumerate() {
+                        *element = self[row][col];
+                    }
+                }
+                ColumnMajor { columns }
+            }
+        }
+
+        impl<S> From<$mat<S>> for ColumnMajor<$mat<S>> where S: FloatType<S> {
+            fn from(m: $mat<S>) -> ColumnMajor<$mat<S>> {
+                m.to_column_major()
+            }
+        }
+
+        impl<S> From<ColumnMajor<$mat<S>>> for $mat<S> where S: FloatType<S> {
+            fn from(m: ColumnMajor<$mat<S>>) -> $mat<S> {
+                m.to_row_major()
+            }
+        }
+
+        #[cfg(feature = "glium")]
+        unsafe impl glium::vertex::Attribute for ColumnMajor<$mat<f32>> {
+            fn get_type() -> glium::vertex::AttributeType {
+                glium::vertex::AttributeType::$attribute_f32
+            }
+
+            fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
+                true
+            }
+        }
+
+        #[cfg(feature = "glium")]
+        unsafe impl glium::vertex::Attribute for ColumnMajor<$mat<f64>> {
+            fn get_type() -> glium::vertex::AttributeType {
+                glium::vertex::AttributeType::$attribute_f64
+            }
+
+            fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
+                true
+            }
+        }
+    };
+    //Only the square matrices can be uniforms in glium
+    ($mat:ident, $vec_r:ident, $c:expr, $r:expr, $attribute_f32:ident, $attribute_f64:ident, $uniform_f32:ident, $uniform_f64:ident) => {
+        impl_column_major!($mat, $vec_r, $c, $r, $attribute_f32, $attribute_f64);
+
+        #[cfg(feature = "glium")]
+        impl glium::uniforms::AsUniformValue for ColumnMajor<$mat<f32>> {
+            fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
+                glium::uniforms::UniformValue::$uniform_f32(self.columns)
+            }
+        }
+
+        #[cfg(feature = "glium")]
+        impl glium::uniforms::AsUniformValue for ColumnMajor<$mat<f64>> {
+            fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
+                glium::uniforms::UniformValue::$uniform_f64(self.columns)
+            }
+        }
+    };
+}
+
+impl_column_major!(Mat2, Vec2, 2, 2, F32x2x2, F64x2x2, Mat2, DoubleMat2);
+impl_column_major!(Mat3, Vec3, 3, 3, F32x3x3, F64x3x3, Mat3, DoubleMat3);
+impl_column_major!(Mat4, Vec4, 4, 4, F32x4x4, F64x4x4, Mat4, DoubleMat4);
+impl_column_major!(Mat2x3, Vec3, 2, 3, F32x2x3, F64x2x3);
+impl_column_major!(Mat2x4, Vec4, 2, 4, F32x2x4, F64x2x4);
+impl_column_major!(Mat3x2, Vec2, 3, 2, F32x3x2, F64x3x2);
+impl_column_major!(Mat3x4, Vec4, 3, 4, F32x3x4, F64x3x4);
+impl_column_major!(Mat4x2, Vec2, 4, 2, F32x4x2, F64x4x2);
+impl_column_major!(Mat4x3, Vec3, 4, 3, F32x4x3, F64x4x3);
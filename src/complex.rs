@@ -0,0 +1,413 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//Complex number, unit complex numbers double as 2D rotations, which makes them the 2D counterpart of Quat
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Complex<S> {
+    pub re: S,
+    pub im: S,
+}
+
+impl<S> Complex<S> where S: FloatType<S> {
+    pub fn identity() -> Complex<S> {
+        Complex {
+            re: S::one(),
+            im: S::zero(),
+        }
+    }
+
+    pub fn zero() -> Complex<S> {
+        Complex {
+            re: S::zero(),
+            im: S::zero(),
+        }
+    }
+
+    pub fn i() -> Complex<S> {
+        Complex {
+            re: S::zero(),
+            im: S::one(),
+        }
+    }
+
+    pub fn new<U>(re: U, im: U) -> Complex<S> where U: InputType {
+        Complex {
+            re: num::cast(re).unwrap(),
+            im: num::cast(im).unwrap(),
+        }
+    }
+
+    pub fn from_polar(r: S, theta: S) -> Complex<S> {
+        Complex {
+            re: r * theta.cos(),
+            im: r * theta.sin(),
+        }
+    }
+
+    //Returns the magnitude and the argument
+    pub fn to_polar(self) -> (S, S) {
+        (self.magnitude(), self.arg())
+    }
+
+    pub fn dot(self, rhs: Complex<S>) -> S {
+        self.re * rhs.re + self.im * rhs.im
+    }
+
+    pub fn magnitude_squared(self) -> S {
+        self.dot(self)
+    }
+
+    pub fn magnitude(self) -> S {
+        self.re.hypot(self.im)
+    }
+
+    //The angle with the real axis, in the range [-pi, pi]
+    pub fn arg(self) -> S {
+        self.im.atan2(self.re)
+    }
+
+    pub fn conjugate(self) -> Complex<S> {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    pub fn inverse(self) -> Complex<S> {
+        self.conjugate() / self.magnitude_squared()
+    }
+
+    pub fn normalized(self) -> Complex<S> {
+        self / self.magnitude()
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.magnitude_squared().approx_eq(S::one(), S::DEF_EPSILON)
+    }
+
+    pub fn is_real(self) -> bool {
+        self.im.approx_eq(S::zero(), S::DEF_EPSILON)
+    }
+
+    pub fn is_imaginary(self) -> bool {
+        self.re.approx_eq(S::zero(), S::DEF_EPSILON)
+    }
+
+    pub fn exp(self) -> Complex<S> {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    //Natural logarithm on the principal branch
+    pub fn ln(self) -> Complex<S> {
+        Complex {
+            re: self.magnitude().ln(),
+            im: self.arg(),
+        }
+    }
+
+    pub fn powi(self, exponent: i32) -> Complex<S> {
+        let mut base = if exponent < 0 { self.inverse() } else { self };
+        let mut exponent = exponent.unsigned_abs();
+        let mut result = Complex::identity();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    pub fn powf(self, exponent: S) -> Complex<S> {
+        if self.magnitude_squared() == S::zero() {
+            return Complex::zero();
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(exponent), theta * exponent)
+    }
+
+    pub fn powc(self, exponent: Complex<S>) -> Complex<S> {
+        if self.magnitude_squared() == S::zero() {
+            return Complex::zero();
+        }
+        (self.ln() * exponent).exp()
+    }
+
+    //Principal square root
+    pub fn sqrt(self) -> Complex<S> {
+        self.powf(num::cast(0.5).unwrap())
+    }
+
+    //The k-th of the n n-th roots, going counter-clockwise starting at the principal root (k = 0),
+    //panics when n is 0, as there is no 0th root
+    pub fn nth_root(self, n: u32, k: u32) -> Complex<S> {
+        assert!(n > 0, "Complex::nth_root needs n > 0");
+        let two_pi: S = num::cast(2.0 * core::f64::consts::PI).unwrap();
+        let n_s: S = num::cast(n).unwrap();
+        let k_s: S = num::cast(k % n).unwrap();
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(S::one() / n_s), (theta + two_pi * k_s) / n_s)
+    }
+
+    //Rotations
+
+    pub fn get_complex_from_angle_deg(theta: S) -> Complex<S> {
+        Complex::get_complex_from_angle_rad(theta.to_radians())
+    }
+
+    //Unit complex number that rotates counter-clockwise by theta
+    pub fn get_complex_from_angle_rad(theta: S) -> Complex<S> {
+        Complex::from_polar(S::one(), theta)
+    }
+
+    pub fn get_angle_deg(self) -> S {
+        self.arg().to_degrees()
+    }
+
+    pub fn get_angle_rad(self) -> S {
+        self.arg()
+    }
+
+    pub fn rotate_deg(&mut self, theta: S) {
+        *self *= Complex::get_complex_from_angle_deg(theta);
+    }
+
+    pub fn rotate_rad(&mut self, theta: S) {
+        *self *= Complex::get_complex_from_angle_rad(theta);
+    }
+
+    pub fn slerp(self, other: Complex<S>, t: S) -> Complex<S> {
+        let delta = (self.conjugate() * other).arg();
+        Complex::from_polar(S::one(), self.arg() + delta * t)
+    }
+
+    pub fn lerp(self, other: Complex<S>, t: S) -> Complex<S> {
+        (self + (other - self) * t).normalized()
+    }
+}
+
+impl<S> Not for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn not(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<S> Neg for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn neg(self) -> Self::Output {
+        Complex {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<S> Add<Complex<S>> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn add(self, rhs: Complex<S>) -> Self::Output {
+        Complex {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl<S> AddAssign<Complex<S>> for Complex<S> where S: FloatType<S> {
+    fn add_assign(&mut self, rhs: Complex<S>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S> Add<S> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn add(self, rhs: S) -> Self::Output {
+        Complex {
+            re: self.re + rhs,
+            im: self.im,
+        }
+    }
+}
+
+impl<S> Sub<Complex<S>> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn sub(self, rhs: Complex<S>) -> Self::Output {
+        Complex {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl<S> SubAssign<Complex<S>> for Complex<S> where S: FloatType<S> {
+    fn sub_assign(&mut self, rhs: Complex<S>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<S> Sub<S> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn sub(self, rhs: S) -> Self::Output {
+        Complex {
+            re: self.re - rhs,
+            im: self.im,
+        }
+    }
+}
+
+impl<S> Mul<Complex<S>> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn mul(self, rhs: Complex<S>) -> Self::Output {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl<S> MulAssign<Complex<S>> for Complex<S> where S: FloatType<S> {
+    fn mul_assign(&mut self, rhs: Complex<S>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S> Mul<S> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Complex {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+}
+
+impl<S> MulAssign<S> for Complex<S> where S: FloatType<S> {
+    fn mul_assign(&mut self, rhs: S) {
+        *self = *self * rhs;
+    }
+}
+
+//Rotates (and scales, for non-unit complex numbers) the vector counter-clockwise
+impl<S> Mul<Vec2<S>> for Complex<S> where S: FloatType<S> {
+    type Output = Vec2<S>;
+
+    fn mul(self, rhs: Vec2<S>) -> Self::Output {
+        Vec2 {
+            x: self.re * rhs.x - self.im * rhs.y,
+            y: self.im * rhs.x + self.re * rhs.y,
+        }
+    }
+}
+
+impl<S> Div<Complex<S>> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    //(a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c^2 + d^2)
+    fn div(self, rhs: Complex<S>) -> Self::Output {
+        let inv_len_sq = S::one() / rhs.magnitude_squared();
+        Complex {
+            re: (self.re * rhs.re + self.im * rhs.im) * inv_len_sq,
+            im: (self.im * rhs.re - self.re * rhs.im) * inv_len_sq,
+        }
+    }
+}
+
+impl<S> DivAssign<Complex<S>> for Complex<S> where S: FloatType<S> {
+    fn div_assign(&mut self, rhs: Complex<S>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<S> Div<S> for Complex<S> where S: FloatType<S> {
+    type Output = Complex<S>;
+
+    fn div(self, rhs: S) -> Self::Output {
+        let inv = S::one() / rhs;
+        self * inv
+    }
+}
+
+impl<S> DivAssign<S> for Complex<S> where S: FloatType<S> {
+    fn div_assign(&mut self, rhs: S) {
+        *self = *self / rhs;
+    }
+}
+
+impl<S> PartialEq for Complex<S> where S: FloatType<S> {
+    fn eq(&self, other: &Complex<S>) -> bool {
+        self.re.approx_eq(other.re, S::DEF_EPSILON) && self.im.approx_eq(other.im, S::DEF_EPSILON)
+    }
+}
+
+impl<S> fmt::Display for Complex<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.3} {:+.3}i", self.re, self.im)
+    }
+}
+
+impl<S> From<(S, S)> for Complex<S> where S: FloatType<S> {
+    fn from(tuple: (S, S)) -> Complex<S> {
+        Complex {
+            re: tuple.0,
+            im: tuple.1,
+        }
+    }
+}
+
+impl<S> From<[S; 2]> for Complex<S> where S: FloatType<S> {
+    fn from(arr: [S; 2]) -> Complex<S> {
+        Complex {
+            re: arr[0],
+            im: arr[1],
+        }
+    }
+}
+
+impl<S> From<Vec2<S>> for Complex<S> where S: FloatType<S> {
+    fn from(v: Vec2<S>) -> Complex<S> {
+        Complex {
+            re: v.x,
+            im: v.y,
+        }
+    }
+}
+
+//Inverse of Mat2::from(Complex), the rotation (and uniform scale) part of the matrix is kept
+impl<S> From<Mat2<S>> for Complex<S> where S: FloatType<S> {
+    fn from(m: Mat2<S>) -> Complex<S> {
+        let half: S = num::cast(0.5).unwrap();
+        Complex {
+            re: (m[0][0] + m[1][1]) * half,
+            im: (m[0][1] - m[1][0]) * half,
+        }
+    }
+}
+
+//The unit complex number that rotates vectors the same way as the rotor, which is its square
+impl<S> From<ga::Rotor2<S>> for Complex<S> where S: FloatType<S> {
+    fn from(r: ga::Rotor2<S>) -> Complex<S> {
+        let r = r.normalized();
+        Complex {
+            re: r.s * r.s - r.xy * r.xy,
+            im: -(S::one() + S::one()) * r.s * r.xy,
+        }
+    }
+}
+
+impl<S> Default for Complex<S> where S: FloatType<S> {
+    fn default() -> Complex<S> {
+        Complex::identity()
+    }
+}
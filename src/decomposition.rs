@@ -0,0 +1,117 @@
+use core::fmt;
+
+use super::*;
+
+//The parts of an affine matrix, which is rebuilt as translation * rotation * shear * scale (applied to column vectors,
+//so the scale comes first), the shear holds the xy, xz and yz factors of an upper triangular shear matrix:
+//x' = x + shear.x * y + shear.y * z, y' = y + shear.z * z
+#[derive(Copy, Clone, Debug)]
+pub struct Decomposition<S> {
+    pub translation: Vec3<S>,
+    pub rotation: Quat<S>,
+    pub scale: Vec3<S>,
+    pub shear: Vec3<S>,
+}
+
+impl<S> Decomposition<S> where S: FloatType<S> {
+    pub fn new(translation: Vec3<S>, rotation: Quat<S>, scale: Vec3<S>, shear: Vec3<S>) -> Decomposition<S> {
+        Decomposition {
+            translation,
+            rotation,
+            scale,
+            shear,
+        }
+    }
+
+    //Splits up the upper left 3x3 part of a matrix with Gram-Schmidt, the columns are the transformed axes,
+    //a negative determinant is put into the x scale so the rotation stays a proper one,
+    //None when the axes are (close to) linearly dependent
+    pub(crate) fn from_axes(translation: Vec3<S>, axes: Mat3<S>) -> Option<Decomposition<S>> {
+        let c0 = Vec3 { x: axes[0][0], y: axes[1][0], z: axes[2][0] };
+        let c1 = Vec3 { x: axes[0][1], y: axes[1][1], z: axes[2][1] };
+        let c2 = Vec3 { x: axes[0][2], y: axes[1][2], z: axes[2][2] };
+
+        let mut scale = Vec3::zero();
+
+        scale.x = c0.length();
+        if scale.x <= S::DEF_EPSILON {
+            return None;
+        }
+        let mut r0 = c0 / scale.x;
+
+        let shear_xy = r0.dot(c1);
+        let c1 = c1 - r0 * shear_xy;
+        scale.y = c1.length();
+        if scale.y <= S::DEF_EPSILON * scale.x {
+            return None;
+        }
+        let r1 = c1 / scale.y;
+
+        let shear_xz = r0.dot(c2);
+        let shear_yz = r1.dot(c2);
+        let c2 = c2 - r0 * shear_xz - r1 * shear_yz;
+        scale.z = c2.length();
+        if scale.z <= S::DEF_EPSILON * scale.x.max(scale.y) {
+            return None;
+        }
+        let r2 = c2 / scale.z;
+
+        let mut shear = Vec3 { x: shear_xy / scale.y, y: shear_xz / scale.z, z: shear_yz / scale.z };
+
+        if r0.cross(r1).dot(r2) < S::zero() {
+            r0 = -r0;
+            scale.x = -scale.x;
+            shear.x = -shear.x;
+            shear.y = -shear.y;
+        }
+
+        //Quat::from(Mat3) expects the rotated axes as rows
+        let rotation = Quat::from(Mat3::new_from_vec3s(r0, r1, r2)).normalized();
+        Some(Decomposition::new(translation, rotation, scale, shear))
+    }
+
+    //The rotation as a matrix for column vectors, so get_rotation_mat() * v == rotation * v
+    pub fn get_rotation_mat(&self) -> Mat3<S> {
+        Mat3::from(self.rotation.normalized()).transpose()
+    }
+
+    pub fn get_shear_mat(&self) -> Mat3<S> {
+        Mat3 {
+            r0: Vec3 { x: S::one(), y: self.shear.x, z: self.shear.y },
+            r1: Vec3 { x: S::zero(), y: S::one(), z: self.shear.z },
+            r2: Vec3 { x: S::zero(), y: S::zero(), z: S::one() },
+        }
+    }
+
+    pub fn has_shear(&self) -> bool {
+        !self.shear.x.approx_eq(S::zero(), S::DEF_EPSILON) ||
+            !self.shear.y.approx_eq(S::zero(), S::DEF_EPSILON) ||
+            !self.shear.z.approx_eq(S::zero(), S::DEF_EPSILON)
+    }
+
+    //Rebuilds the matrix that was decomposed
+    pub fn recompose(&self) -> Mat4<S> {
+        Mat4::from(*self)
+    }
+}
+
+impl<S> PartialEq for Decomposition<S> where S: FloatType<S> {
+    fn eq(&self, other: &Decomposition<S>) -> bool {
+        self.translation == other.translation &&
+            (self.rotation == other.rotation || self.rotation == -other.rotation) &&
+            self.scale == other.scale &&
+            self.shear == other.shear
+    }
+}
+
+impl<S> fmt::Display for Decomposition<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "T: {} R: {} S: {} Sh: {}", self.translation, self.rotation, self.scale, self.shear)
+    }
+}
+
+impl<S> From<Transform<S>> for Decomposition<S> where S: FloatType<S> {
+    fn from(t: Transform<S>) -> Decomposition<S> {
+        Decomposition::new(t.translation, t.rotation, t.scale, Vec3::zero())
+    }
+}
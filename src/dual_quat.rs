@@ -0,0 +1,297 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//Dual quaternion real + ε dual (with ε² = 0), unit dual quaternions describe rigid transforms (rotation and translation),
+//they are built as real = rotation and dual = 0.5 * translation * rotation, so the rotation is applied first,
+//and work the same way as Quat, so DualQuat::from_rotation(q).transform_point(p) == q * p
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct DualQuat<S> {
+    pub real: Quat<S>,
+    pub dual: Quat<S>,
+}
+
+impl<S> DualQuat<S> where S: FloatType<S> {
+    pub fn identity() -> DualQuat<S> {
+        DualQuat {
+            real: Quat::identity(),
+            dual: Quat { w: S::zero(), v: Vec3::zero() },
+        }
+    }
+
+    pub fn new(real: Quat<S>, dual: Quat<S>) -> DualQuat<S> {
+        DualQuat {
+            real,
+            dual,
+        }
+    }
+
+    //Rotates first, then translates, the rotation should be a unit quaternion
+    pub fn from_rotation_translation(rotation: Quat<S>, translation: Vec3<S>) -> DualQuat<S> {
+        let half: S = num::cast(0.5).unwrap();
+        DualQuat {
+            real: rotation,
+            dual: Quat { w: S::zero(), v: translation } * rotation * half,
+        }
+    }
+
+    pub fn from_rotation(rotation: Quat<S>) -> DualQuat<S> {
+        DualQuat::from_rotation_translation(rotation, Vec3::zero())
+    }
+
+    //Decomposes the matrix, scale and shear can not be represented and are dropped,
+    //None for singular matrices and projections, which can not be decomposed
+    pub fn try_from_mat4(m: Mat4<S>) -> Option<DualQuat<S>> {
+        m.decompose().map(|d| DualQuat::from(Transform::from(d)))
+    }
+
+    pub fn from_translation(translation: Vec3<S>) -> DualQuat<S> {
+        DualQuat::from_rotation_translation(Quat::identity(), translation)
+    }
+
+    pub fn get_rotation(&self) -> Quat<S> {
+        self.real
+    }
+
+    //Only valid for unit dual quaternions, normalize first otherwise
+    pub fn get_translation(&self) -> Vec3<S> {
+        let two = S::one() + S::one();
+        (self.dual * self.real.conjugate()).v * two
+    }
+
+    //The quaternion conjugate of both parts, this is the inverse of a unit dual quaternion
+    pub fn conjugate(&self) -> DualQuat<S> {
+        DualQuat {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    //real - ε dual
+    pub fn dual_conjugate(&self) -> DualQuat<S> {
+        DualQuat {
+            real: self.real,
+            dual: -self.dual,
+        }
+    }
+
+    //Both conjugates at once, this is the one that transforms points as q p q̄ with p = 1 + ε p
+    pub fn combined_conjugate(&self) -> DualQuat<S> {
+        DualQuat {
+            real: self.real.conjugate(),
+            dual: -self.dual.conjugate(),
+        }
+    }
+
+    //Unchecked, a zero real part gives non-finite components
+    pub fn inverse(&self) -> DualQuat<S> {
+        let real = self.real.inverse();
+        DualQuat {
+            real,
+            dual: -(real * self.dual * real),
+        }
+    }
+
+    //The real part of the dual number magnitude, the dual part is real.dot(dual) / magnitude
+    pub fn magnitude(&self) -> S {
+        self.real.magnitude()
+    }
+
+    //Makes the real part unit length and the dual part perpendicular to it, which is what is needed to be a rigid transform
+    pub fn normalized(&self) -> DualQuat<S> {
+        let inv = S::one() / self.real.magnitude();
+        let real = self.real * inv;
+        let dual = self.dual * inv;
+        DualQuat {
+            real,
+            dual: dual - real * real.dot(dual),
+        }
+    }
+
+    pub fn is_unit(&self) -> bool {
+        self.real.is_unit() && self.real.dot(self.dual).approx_eq(S::zero(), S::DEF_EPSILON)
+    }
+
+    pub fn dot(&self, rhs: DualQuat<S>) -> S {
+        self.real.dot(rhs.real)
+    }
+
+    //Only valid for unit dual quaternions, normalize first otherwise
+    pub fn transform_point(&self, p: Vec3<S>) -> Vec3<S> {
+        self.real * p + self.get_translation()
+    }
+
+    //Directions and offsets, these are not affected by the translation
+    pub fn transform_vector(&self, v: Vec3<S>) -> Vec3<S> {
+        self.real * v
+    }
+
+    //Scales the screw motion (the rotation around and the translation along the same axis), for unit dual quaternions
+    pub fn pow(&self, exponent: S) -> DualQuat<S> {
+        let half: S = num::cast(0.5).unwrap();
+        let two = S::one() + S::one();
+        let half_theta = self.real.w.max(-S::one()).min(S::one()).acos();
+        let sin_half_theta = half_theta.sin();
+
+        //No rotation, so only the translation is left to scale
+        if sin_half_theta.abs() < num::cast(0.0001).unwrap() {
+            return DualQuat::from_translation(self.get_translation() * exponent);
+        }
+
+        let axis = self.real.v / sin_half_theta;
+        let pitch = -two * self.dual.w / sin_half_theta;
+        let moment = (self.dual.v - axis * (pitch * half * half_theta.cos())) / sin_half_theta;
+
+        let new_half_theta = half_theta * exponent;
+        let new_pitch = pitch * exponent;
+        let (sin, cos) = new_half_theta.sin_cos();
+        DualQuat {
+            real: Quat { w: cos, v: axis * sin },
+            dual: Quat {
+                w: -new_pitch * half * sin,
+                v: moment * sin + axis * (new_pitch * half * cos),
+            },
+        }
+    }
+
+    //Screw linear interpolation, moves along a single screw motion at a constant speed, both should be unit dual quaternions
+    pub fn sclerp(&self, other: DualQuat<S>, t: S) -> DualQuat<S> {
+        let other = if self.dot(other) < S::zero() { -other } else { other };
+        *self * (self.conjugate() * other).pow(t)
+    }
+
+    //Dual quaternion linear blending between 2 transforms, cheaper than sclerp but not at a constant speed
+    pub fn lerp(&self, other: DualQuat<S>, t: S) -> DualQuat<S> {
+        DualQuat::blend(&[*self, other], &[S::one() - t, t])
+    }
+
+    //Dual quaternion linear blending (DLB) for skinning, the weighted sum of the transforms, normalized,
+    //all of them are flipped to the same hemisphere as the first one, so the blend does not take the long way around,
+    //panics when there is nothing to blend or the number of weights does not match
+    pub fn blend(dual_quats: &[DualQuat<S>], weights: &[S]) -> DualQuat<S> {
+        assert!(!dual_quats.is_empty(), "DualQuat::blend needs at least one dual quaternion");
+        assert_eq!(dual_quats.len(), weights.len(), "DualQuat::blend needs one weight per dual quaternion");
+        let pivot = dual_quats[0].real;
+        let mut sum = DualQuat::new(Quat { w: S::zero(), v: Vec3::zero() }, Quat { w: S::zero(), v: Vec3::zero() });
+        for (dq, &weight) in dual_quats.iter().zip(weights) {
+            let weight = if pivot.dot(dq.real) < S::zero() { -weight } else { weight };
+            sum = sum + *dq * weight;
+        }
+        sum.normalized()
+    }
+
+    pub fn get_mat(&self) -> Mat4<S> {
+        Mat4::from(*self)
+    }
+}
+
+impl<S> Not for DualQuat<S> where S: FloatType<S> {
+    type Output = DualQuat<S>;
+
+    fn not(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<S> Neg for DualQuat<S> where S: FloatType<S> {
+    type Output = DualQuat<S>;
+
+    fn neg(self) -> Self::Output {
+        DualQuat {
+            real: -self.real,
+            dual: -self.dual,
+        }
+    }
+}
+
+impl<S> Add<DualQuat<S>> for DualQuat<S> where S: FloatType<S> {
+    type Output = DualQuat<S>;
+
+    fn add(self, rhs: DualQuat<S>) -> Self::Output {
+        DualQuat {
+            real: self.real + rhs.real,
+            dual: self.dual + rhs.dual,
+        }
+    }
+}
+
+impl<S> Sub<DualQuat<S>> for DualQuat<S> where S: FloatType<S> {
+    type Output = DualQuat<S>;
+
+    fn sub(self, rhs: DualQuat<S>) -> Self::Output {
+        DualQuat {
+            real: self.real - rhs.real,
+            dual: self.dual - rhs.dual,
+        }
+    }
+}
+
+//Applies rhs first, then self, like Quat * Quat
+impl<S> Mul<DualQuat<S>> for DualQuat<S> where S: FloatType<S> {
+    type Output = DualQuat<S>;
+
+    fn mul(self, rhs: DualQuat<S>) -> Self::Output {
+        DualQuat {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+
+impl<S> MulAssign<DualQuat<S>> for DualQuat<S> where S: FloatType<S> {
+    fn mul_assign(&mut self, rhs: DualQuat<S>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S> Mul<S> for DualQuat<S> where S: FloatType<S> {
+    type Output = DualQuat<S>;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        DualQuat {
+            real: self.real * rhs,
+            dual: self.dual * rhs,
+        }
+    }
+}
+
+impl<S> Mul<Vec3<S>> for DualQuat<S> where S: FloatType<S> {
+    type Output = Vec3<S>;
+
+    fn mul(self, rhs: Vec3<S>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+
+impl<S> PartialEq for DualQuat<S> where S: FloatType<S> {
+    fn eq(&self, other: &DualQuat<S>) -> bool {
+        self.real == other.real && self.dual == other.dual
+    }
+}
+
+impl<S> fmt::Display for DualQuat<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + ε({})", self.real, self.dual)
+    }
+}
+
+//The scale can not be represented and is dropped
+impl<S> From<Transform<S>> for DualQuat<S> where S: FloatType<S> {
+    fn from(t: Transform<S>) -> DualQuat<S> {
+        DualQuat::from_rotation_translation(t.rotation.normalized(), t.translation)
+    }
+}
+
+impl<S> From<Quat<S>> for DualQuat<S> where S: FloatType<S> {
+    fn from(q: Quat<S>) -> DualQuat<S> {
+        DualQuat::from_rotation(q)
+    }
+}
+
+impl<S> Default for DualQuat<S> where S: FloatType<S> {
+    fn default() -> DualQuat<S> {
+        DualQuat::identity()
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//The oriented plane spanned by 2 vectors, xy is the component in the plane going from x towards y, and so on
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Bivector3<S> {
+    pub xy: S,
+    pub yz: S,
+    pub zx: S,
+}
+
+impl<S> Bivector3<S> where S: FloatType<S> {
+    pub fn zero() -> Bivector3<S> {
+        Bivector3 {
+            xy: S::zero(),
+            yz: S::zero(),
+            zx: S::zero(),
+        }
+    }
+
+    pub fn new<U>(xy: U, yz: U, zx: U) -> Bivector3<S> where U: InputType {
+        Bivector3 {
+            xy: num::cast(xy).unwrap(),
+            yz: num::cast(yz).unwrap(),
+            zx: num::cast(zx).unwrap(),
+        }
+    }
+
+    //The unit planes
+    pub fn xy() -> Bivector3<S> {
+        Bivector3 { xy: S::one(), ..Bivector3::zero() }
+    }
+
+    pub fn yz() -> Bivector3<S> {
+        Bivector3 { yz: S::one(), ..Bivector3::zero() }
+    }
+
+    pub fn zx() -> Bivector3<S> {
+        Bivector3 { zx: S::one(), ..Bivector3::zero() }
+    }
+
+    //The vector perpendicular to the plane (the Hodge dual), a.wedge(b).get_normal() is a.cross(b)
+    pub fn get_normal(&self) -> Vec3<S> {
+        Vec3 {
+            x: self.yz,
+            y: self.zx,
+            z: self.xy,
+        }
+    }
+
+    //The plane perpendicular to the vector, the inverse of get_normal
+    pub fn from_normal(n: Vec3<S>) -> Bivector3<S> {
+        Bivector3 {
+            xy: n.z,
+            yz: n.x,
+            zx: n.y,
+        }
+    }
+
+    pub fn dot(self, rhs: Bivector3<S>) -> S {
+        self.xy * rhs.xy + self.yz * rhs.yz + self.zx * rhs.zx
+    }
+
+    //The area of the parallelogram that spans the plane, squared
+    pub fn magnitude_squared(self) -> S {
+        self.dot(self)
+    }
+
+    pub fn magnitude(self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalized(self) -> Bivector3<S> {
+        self / self.magnitude()
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.magnitude_squared().approx_eq(S::one(), S::DEF_EPSILON)
+    }
+}
+
+impl<S> Neg for Bivector3<S> where S: FloatType<S> {
+    type Output = Bivector3<S>;
+
+    fn neg(self) -> Self::Output {
+        Bivector3 {
+            xy: -self.xy,
+            yz: -self.yz,
+            zx: -self.zx,
+        }
+    }
+}
+
+impl<S> Add<Bivector3<S>> for Bivector3<S> where S: FloatType<S> {
+    type Output = Bivector3<S>;
+
+    fn add(self, rhs: Bivector3<S>) -> Self::Output {
+        Bivector3 {
+            xy: self.xy + rhs.xy,
+            yz: self.yz + rhs.yz,
+            zx: self.zx + rhs.zx,
+        }
+    }
+}
+
+impl<S> Sub<Bivector3<S>> for Bivector3<S> where S: FloatType<S> {
+    type Output = Bivector3<S>;
+
+    fn sub(self, rhs: Bivector3<S>) -> Self::Output {
+        Bivector3 {
+            xy: self.xy - rhs.xy,
+            yz: self.yz - rhs.yz,
+            zx: self.zx - rhs.zx,
+        }
+    }
+}
+
+impl<S> Mul<S> for Bivector3<S> where S: FloatType<S> {
+    type Output = Bivector3<S>;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Bivector3 {
+            xy: self.xy * rhs,
+            yz: self.yz * rhs,
+            zx: self.zx * rhs,
+        }
+    }
+}
+
+impl<S> Div<S> for Bivector3<S> where S: FloatType<S> {
+    type Output = Bivector3<S>;
+
+    fn div(self, rhs: S) -> Self::Output {
+        let inv = S::one() / rhs;
+        self * inv
+    }
+}
+
+impl<S> PartialEq for Bivector3<S> where S: FloatType<S> {
+    fn eq(&self, other: &Bivector3<S>) -> bool {
+        self.xy.approx_eq(other.xy, S::DEF_EPSILON) &&
+            self.yz.approx_eq(other.yz, S::DEF_EPSILON) &&
+            self.zx.approx_eq(other.zx, S::DEF_EPSILON)
+    }
+}
+
+impl<S> fmt::Display for Bivector3<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:.3}xy {:+.3}yz {:+.3}zx)", self.xy, self.yz, self.zx)
+    }
+}
+
+impl<S> Default for Bivector3<S> where S: FloatType<S> {
+    fn default() -> Bivector3<S> {
+        Bivector3::zero()
+    }
+}
@@ -0,0 +1,22 @@
+//Geometric algebra, bivectors are oriented planes (a.wedge(b) goes from a towards b), and rotors rotate within such a plane,
+//which makes the direction of a rotation independent of the handedness of the coordinate system,
+//the rotors map one to one onto Quat and Complex, so both can be mixed freely
+
+use super::*;
+
+pub use self::bivector3::*;
+pub use self::rotor2::*;
+pub use self::rotor3::*;
+
+pub mod bivector3;
+pub mod rotor2;
+pub mod rotor3;
+
+pub type Bivector3n = Bivector3<f32>;
+pub type Bivector3h = Bivector3<f64>;
+
+pub type Rotor2n = Rotor2<f32>;
+pub type Rotor2h = Rotor2<f64>;
+
+pub type Rotor3n = Rotor3<f32>;
+pub type Rotor3h = Rotor3<f64>;
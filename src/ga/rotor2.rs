@@ -0,0 +1,208 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//A scalar plus the xy bivector, positive angles rotate from x towards y, vectors get rotated by the sandwich product r v r̃,
+//which is what Rotor2 * Vec2 does, every rotor turns by half its angle on each side, so the angle is twice that of a Complex
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Rotor2<S> {
+    pub s: S,
+    pub xy: S,
+}
+
+impl<S> Rotor2<S> where S: FloatType<S> {
+    pub fn identity() -> Rotor2<S> {
+        Rotor2 {
+            s: S::one(),
+            xy: S::zero(),
+        }
+    }
+
+    pub fn new<U>(s: U, xy: U) -> Rotor2<S> where U: InputType {
+        Rotor2 {
+            s: num::cast(s).unwrap(),
+            xy: num::cast(xy).unwrap(),
+        }
+    }
+
+    pub fn get_rotor_from_angle_deg(theta: S) -> Rotor2<S> {
+        Rotor2::get_rotor_from_angle_rad(theta.to_radians())
+    }
+
+    pub fn get_rotor_from_angle_rad(theta: S) -> Rotor2<S> {
+        let half_theta = theta * num::cast(0.5).unwrap();
+        Rotor2 {
+            s: half_theta.cos(),
+            xy: -half_theta.sin(),
+        }
+    }
+
+    //The shortest rotation that takes the direction of from to the direction of to
+    pub fn get_rotor_from_to(from: Vec2<S>, to: Vec2<S>) -> Rotor2<S> {
+        let theta = (from.x * to.y - from.y * to.x).atan2(from.dot(to));
+        Rotor2::get_rotor_from_angle_rad(theta)
+    }
+
+    pub fn get_angle_deg(self) -> S {
+        self.get_angle_rad().to_degrees()
+    }
+
+    //In the range [-2pi, 2pi]
+    pub fn get_angle_rad(self) -> S {
+        (S::one() + S::one()) * (-self.xy).atan2(self.s)
+    }
+
+    pub fn dot(self, rhs: Rotor2<S>) -> S {
+        self.s * rhs.s + self.xy * rhs.xy
+    }
+
+    pub fn magnitude_squared(self) -> S {
+        self.dot(self)
+    }
+
+    pub fn magnitude(self) -> S {
+        self.s.hypot(self.xy)
+    }
+
+    pub fn normalized(self) -> Rotor2<S> {
+        let inv = S::one() / self.magnitude();
+        Rotor2 {
+            s: self.s * inv,
+            xy: self.xy * inv,
+        }
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.magnitude_squared().approx_eq(S::one(), S::DEF_EPSILON)
+    }
+
+    //Undoes the rotation for unit rotors
+    pub fn reverse(self) -> Rotor2<S> {
+        Rotor2 {
+            s: self.s,
+            xy: -self.xy,
+        }
+    }
+
+    //Unchecked, the zero rotor gives non-finite components
+    pub fn inverse(self) -> Rotor2<S> {
+        let inv = S::one() / self.magnitude_squared();
+        Rotor2 {
+            s: self.s * inv,
+            xy: -self.xy * inv,
+        }
+    }
+
+    //Takes the shortest way around
+    pub fn slerp(self, other: Rotor2<S>, t: S) -> Rotor2<S> {
+        let delta = self.reverse() * other;
+        let delta = if delta.s < S::zero() { -delta } else { delta };
+        self * Rotor2::get_rotor_from_angle_rad(delta.get_angle_rad() * t)
+    }
+
+    pub fn lerp(self, other: Rotor2<S>, t: S) -> Rotor2<S> {
+        let other = if self.dot(other) < S::zero() { -other } else { other };
+        Rotor2 {
+            s: self.s + (other.s - self.s) * t,
+            xy: self.xy + (other.xy - self.xy) * t,
+        }.normalized()
+    }
+
+    pub fn rotate_deg(&mut self, theta: S) {
+        *self *= Rotor2::get_rotor_from_angle_deg(theta);
+    }
+
+    pub fn rotate_rad(&mut self, theta: S) {
+        *self *= Rotor2::get_rotor_from_angle_rad(theta);
+    }
+}
+
+impl<S> Not for Rotor2<S> where S: FloatType<S> {
+    type Output = Rotor2<S>;
+
+    fn not(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<S> Neg for Rotor2<S> where S: FloatType<S> {
+    type Output = Rotor2<S>;
+
+    fn neg(self) -> Self::Output {
+        Rotor2 {
+            s: -self.s,
+            xy: -self.xy,
+        }
+    }
+}
+
+//The geometric product, 2D rotations commute, so the order does not matter
+impl<S> Mul<Rotor2<S>> for Rotor2<S> where S: FloatType<S> {
+    type Output = Rotor2<S>;
+
+    fn mul(self, rhs: Rotor2<S>) -> Self::Output {
+        Rotor2 {
+            s: self.s * rhs.s - self.xy * rhs.xy,
+            xy: self.s * rhs.xy + self.xy * rhs.s,
+        }
+    }
+}
+
+impl<S> Mul<S> for Rotor2<S> where S: FloatType<S> {
+    type Output = Rotor2<S>;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Rotor2 {
+            s: self.s * rhs,
+            xy: self.xy * rhs,
+        }
+    }
+}
+
+impl<S> MulAssign<Rotor2<S>> for Rotor2<S> where S: FloatType<S> {
+    fn mul_assign(&mut self, rhs: Rotor2<S>) {
+        *self = *self * rhs;
+    }
+}
+
+//The sandwich product r v r⁻¹
+impl<S> Mul<Vec2<S>> for Rotor2<S> where S: FloatType<S> {
+    type Output = Vec2<S>;
+
+    fn mul(self, rhs: Vec2<S>) -> Self::Output {
+        let inv = S::one() / self.magnitude_squared();
+        let cos = (self.s * self.s - self.xy * self.xy) * inv;
+        let sin = -(S::one() + S::one()) * self.s * self.xy * inv;
+        Vec2 {
+            x: cos * rhs.x - sin * rhs.y,
+            y: sin * rhs.x + cos * rhs.y,
+        }
+    }
+}
+
+impl<S> PartialEq for Rotor2<S> where S: FloatType<S> {
+    fn eq(&self, other: &Rotor2<S>) -> bool {
+        self.s.approx_eq(other.s, S::DEF_EPSILON) && self.xy.approx_eq(other.xy, S::DEF_EPSILON)
+    }
+}
+
+impl<S> fmt::Display for Rotor2<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.3} {:+.3}xy", self.s, self.xy)
+    }
+}
+
+//The rotor that rotates by the argument of the complex number
+impl<S> From<Complex<S>> for Rotor2<S> where S: FloatType<S> {
+    fn from(c: Complex<S>) -> Rotor2<S> {
+        Rotor2::get_rotor_from_angle_rad(c.arg())
+    }
+}
+
+impl<S> Default for Rotor2<S> where S: FloatType<S> {
+    fn default() -> Rotor2<S> {
+        Rotor2::identity()
+    }
+}
@@ -0,0 +1,220 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//A scalar plus a bivector, get_rotor_from_angle_plane(theta, a.wedge(b)) rotates by theta from a towards b,
+//vectors get rotated by the sandwich product r v r̃, which is what Rotor3 * Vec3 does
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Rotor3<S> {
+    pub s: S,
+    pub b: Bivector3<S>,
+}
+
+impl<S> Rotor3<S> where S: FloatType<S> {
+    pub fn identity() -> Rotor3<S> {
+        Rotor3 {
+            s: S::one(),
+            b: Bivector3::zero(),
+        }
+    }
+
+    pub fn new(s: S, b: Bivector3<S>) -> Rotor3<S> {
+        Rotor3 {
+            s,
+            b,
+        }
+    }
+
+    //Rotates by theta within a unit plane, going from the first towards the second vector of the plane
+    pub fn get_rotor_from_angle_plane(theta: S, plane: Bivector3<S>) -> Rotor3<S> {
+        debug_assert!(plane.is_unit());
+        let half_theta = theta * num::cast(0.5).unwrap();
+        Rotor3 {
+            s: half_theta.cos(),
+            b: -plane * half_theta.sin(),
+        }
+    }
+
+    pub fn get_angle_plane_from_rotor(&self) -> (Bivector3<S>, S) {
+        let r = self.normalized();
+        let theta = (S::one() + S::one()) * r.b.magnitude().atan2(r.s);
+        if r.b.magnitude() < num::cast(0.0001).unwrap() {
+            (Bivector3::xy(), theta)
+        } else {
+            (-r.b.normalized(), theta)
+        }
+    }
+
+    //The shortest rotation that takes the direction of from to the direction of to
+    pub fn get_rotor_from_to(from: Vec3<S>, to: Vec3<S>) -> Rotor3<S> {
+        let from = from.normalized();
+        let to = to.normalized();
+        let s = S::one() + from.dot(to);
+        if s < num::cast(0.0001).unwrap() {
+            //Opposite directions, any plane that contains from will do
+            let other = if from.x.abs() < num::cast(0.9).unwrap() { Vec3::right() } else { Vec3::up() };
+            Rotor3 {
+                s: S::zero(),
+                b: from.wedge(other).normalized(),
+            }
+        } else {
+            Rotor3 {
+                s,
+                b: -from.wedge(to),
+            }.normalized()
+        }
+    }
+
+    pub fn dot(self, rhs: Rotor3<S>) -> S {
+        self.s * rhs.s + self.b.dot(rhs.b)
+    }
+
+    pub fn magnitude_squared(self) -> S {
+        self.dot(self)
+    }
+
+    pub fn magnitude(self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalized(self) -> Rotor3<S> {
+        let inv = S::one() / self.magnitude();
+        Rotor3 {
+            s: self.s * inv,
+            b: self.b * inv,
+        }
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.magnitude_squared().approx_eq(S::one(), S::DEF_EPSILON)
+    }
+
+    //Flips the order of the vectors in the product, which undoes the rotation for unit rotors
+    pub fn reverse(self) -> Rotor3<S> {
+        Rotor3 {
+            s: self.s,
+            b: -self.b,
+        }
+    }
+
+    //Unchecked, the zero rotor gives non-finite components
+    pub fn inverse(self) -> Rotor3<S> {
+        let inv = S::one() / self.magnitude_squared();
+        Rotor3 {
+            s: self.s * inv,
+            b: -self.b * inv,
+        }
+    }
+
+    pub fn slerp(self, other: Rotor3<S>, t: S) -> Rotor3<S> {
+        Rotor3::from(Quat::from(self).slerp(Quat::from(other), t))
+    }
+
+    pub fn lerp(self, other: Rotor3<S>, t: S) -> Rotor3<S> {
+        Rotor3::from(Quat::from(self).lerp(Quat::from(other), t))
+    }
+
+    //Rotates the rotor further, by theta within a unit plane
+    pub fn rotate_in_plane(&mut self, theta: S, plane: Bivector3<S>) {
+        *self = Rotor3::get_rotor_from_angle_plane(theta, plane) * *self;
+    }
+}
+
+impl<S> Not for Rotor3<S> where S: FloatType<S> {
+    type Output = Rotor3<S>;
+
+    fn not(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<S> Neg for Rotor3<S> where S: FloatType<S> {
+    type Output = Rotor3<S>;
+
+    fn neg(self) -> Self::Output {
+        Rotor3 {
+            s: -self.s,
+            b: -self.b,
+        }
+    }
+}
+
+//The geometric product, applies rhs first, then self
+impl<S> Mul<Rotor3<S>> for Rotor3<S> where S: FloatType<S> {
+    type Output = Rotor3<S>;
+
+    fn mul(self, rhs: Rotor3<S>) -> Self::Output {
+        let lhs_n = self.b.get_normal();
+        let rhs_n = rhs.b.get_normal();
+        Rotor3 {
+            s: self.s * rhs.s - self.b.dot(rhs.b),
+            b: Bivector3::from_normal(rhs_n * self.s + lhs_n * rhs.s - lhs_n.cross(rhs_n)),
+        }
+    }
+}
+
+impl<S> Mul<S> for Rotor3<S> where S: FloatType<S> {
+    type Output = Rotor3<S>;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Rotor3 {
+            s: self.s * rhs,
+            b: self.b * rhs,
+        }
+    }
+}
+
+impl<S> MulAssign<Rotor3<S>> for Rotor3<S> where S: FloatType<S> {
+    fn mul_assign(&mut self, rhs: Rotor3<S>) {
+        *self = *self * rhs;
+    }
+}
+
+//The sandwich product r v r⁻¹
+impl<S> Mul<Vec3<S>> for Rotor3<S> where S: FloatType<S> {
+    type Output = Vec3<S>;
+
+    fn mul(self, rhs: Vec3<S>) -> Self::Output {
+        let two = S::one() + S::one();
+        let n = self.b.get_normal();
+        let n_cross_v = n.cross(rhs);
+        rhs + (n.cross(n_cross_v) - n_cross_v * self.s) * (two / self.magnitude_squared())
+    }
+}
+
+impl<S> PartialEq for Rotor3<S> where S: FloatType<S> {
+    fn eq(&self, other: &Rotor3<S>) -> bool {
+        self.s.approx_eq(other.s, S::DEF_EPSILON) && self.b == other.b
+    }
+}
+
+impl<S> fmt::Display for Rotor3<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.3} + {}", self.s, self.b)
+    }
+}
+
+//The rotor and the quaternion that rotate vectors the same way
+impl<S> From<Quat<S>> for Rotor3<S> where S: FloatType<S> {
+    fn from(q: Quat<S>) -> Rotor3<S> {
+        Rotor3 {
+            s: q.w,
+            b: Bivector3::from_normal(-q.v),
+        }
+    }
+}
+
+//Same as going through Quat
+impl<S> From<Mat3<S>> for Rotor3<S> where S: FloatType<S> {
+    fn from(m: Mat3<S>) -> Rotor3<S> {
+        Rotor3::from(Quat::from(m))
+    }
+}
+
+impl<S> Default for Rotor3<S> where S: FloatType<S> {
+    fn default() -> Rotor3<S> {
+        Rotor3::identity()
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+//Axis aligned bounding box
+#[derive(Copy, Clone, Debug)]
+pub struct Aabb<S> {
+    pub min: Vec3<S>,
+    pub max: Vec3<S>,
+}
+
+impl<S> Aabb<S> where S: FloatType<S> {
+    //The corners can be given in any order
+    pub fn new(a: Vec3<S>, b: Vec3<S>) -> Aabb<S> {
+        Aabb {
+            min: component_min(a, b),
+            max: component_max(a, b),
+        }
+    }
+
+    pub fn from_center_half_extents(center: Vec3<S>, half_extents: Vec3<S>) -> Aabb<S> {
+        Aabb::new(center - half_extents, center + half_extents)
+    }
+
+    pub fn from_points(points: &[Vec3<S>]) -> Aabb<S> {
+        if points.is_empty() {
+            return Aabb::new(Vec3::zero(), Vec3::zero());
+        }
+        points.iter().fold(Aabb { min: points[0], max: points[0] }, |aabb, &p| aabb.expanded_to(p))
+    }
+
+    pub fn center(&self) -> Vec3<S> {
+        let half: S = num::cast(0.5).unwrap();
+        (self.min + self.max) * half
+    }
+
+    pub fn half_extents(&self) -> Vec3<S> {
+        let half: S = num::cast(0.5).unwrap();
+        (self.max - self.min) * half
+    }
+
+    pub fn size(&self) -> Vec3<S> {
+        self.max - self.min
+    }
+
+    //Ordered by the bits of the index, bit 0 picks max.x, bit 1 max.y and bit 2 max.z
+    pub fn corners(&self) -> [Vec3<S>; 8] {
+        let mut corners = [self.min; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            *corner = Vec3 {
+                x: if i & 1 != 0 { self.max.x } else { self.min.x },
+                y: if i & 2 != 0 { self.max.y } else { self.min.y },
+                z: if i & 4 != 0 { self.max.z } else { self.min.z },
+            };
+        }
+        corners
+    }
+
+    pub fn expanded_to(&self, p: Vec3<S>) -> Aabb<S> {
+        Aabb {
+            min: component_min(self.min, p),
+            max: component_max(self.max, p),
+        }
+    }
+
+    pub fn merged(&self, other: &Aabb<S>) -> Aabb<S> {
+        Aabb {
+            min: component_min(self.min, other.min),
+            max: component_max(self.max, other.max),
+        }
+    }
+
+    //The box that encloses the transformed box (Arvo's method), only valid for affine transforms
+    pub fn transformed(&self, m: Mat4<S>) -> Aabb<S> {
+        let rot_scale = Mat3::from(m);
+        let abs = Mat3 {
+            r0: Vec3 { x: rot_scale[0][0].abs(), y: rot_scale[0][1].abs(), z: rot_scale[0][2].abs() },
+            r1: Vec3 { x: rot_scale[1][0].abs(), y: rot_scale[1][1].abs(), z: rot_scale[1][2].abs() },
+            r2: Vec3 { x: rot_scale[2][0].abs(), y: rot_scale[2][1].abs(), z: rot_scale[2][2].abs() },
+        };
+        Aabb::from_center_half_extents(transform_point(m, self.center()), abs * self.half_extents())
+    }
+
+    pub fn contains_point(&self, p: Vec3<S>) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+            p.y >= self.min.y && p.y <= self.max.y &&
+            p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    //Points inside the box are their own closest point
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        component_min(component_max(p, self.min), self.max)
+    }
+
+    //Negative inside, positive outside
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        box_signed_distance(p - self.center(), self.half_extents())
+    }
+}
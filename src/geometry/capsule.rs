@@ -0,0 +1,46 @@
+use super::*;
+
+//All the points within radius of the segment
+#[derive(Copy, Clone, Debug)]
+pub struct Capsule<S> {
+    pub segment: LineSegment<S>,
+    pub radius: S,
+}
+
+impl<S> Capsule<S> where S: FloatType<S> {
+    pub fn new(start: Vec3<S>, end: Vec3<S>, radius: S) -> Capsule<S> {
+        Capsule {
+            segment: LineSegment::new(start, end),
+            radius,
+        }
+    }
+
+    //Upright capsule like the ones used for character controllers, the height includes the caps
+    pub fn new_upright(base: Vec3<S>, height: S, radius: S) -> Capsule<S> {
+        let cylinder_height = (height - radius - radius).max(S::zero());
+        let start = base + Vec3::up() * radius;
+        Capsule::new(start, start + Vec3::up() * cylinder_height, radius)
+    }
+
+    //The radius is scaled by the largest factor to keep the result enclosed
+    pub fn transformed(&self, m: Mat4<S>) -> Capsule<S> {
+        Capsule {
+            segment: self.segment.transformed(m),
+            radius: self.radius * get_max_scale(m),
+        }
+    }
+
+    pub fn contains_point(&self, p: Vec3<S>) -> bool {
+        (p - self.segment.closest_point(p)).length_squared() <= self.radius * self.radius
+    }
+
+    //Points inside the capsule are their own closest point
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        Sphere::new(self.segment.closest_point(p), self.radius).closest_point(p)
+    }
+
+    //Negative inside, positive outside
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        self.segment.distance(p) - self.radius
+    }
+}
@@ -0,0 +1,189 @@
+use super::*;
+
+//How a volume relates to a frustum, for culling
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+//Convex volume bounded by six planes, the normals of the planes point into the frustum,
+//the far plane is None for frustums that go on forever (infinite projections)
+#[derive(Copy, Clone, Debug)]
+pub struct Frustum<S> {
+    pub left: Plane<S>,
+    pub right: Plane<S>,
+    pub bottom: Plane<S>,
+    pub top: Plane<S>,
+    pub near: Plane<S>,
+    pub far: Option<Plane<S>>,
+}
+
+impl<S> Frustum<S> where S: FloatType<S> {
+    pub fn new(left: Plane<S>, right: Plane<S>, bottom: Plane<S>, top: Plane<S>, near: Plane<S>, far: Plane<S>) -> Frustum<S> {
+        Frustum { left, right, bottom, top, near, far: Some(far) }
+    }
+
+    pub fn new_infinite(left: Plane<S>, right: Plane<S>, bottom: Plane<S>, top: Plane<S>, near: Plane<S>) -> Frustum<S> {
+        Frustum { left, right, bottom, top, near, far: None }
+    }
+
+    //The planes in the order left, right, bottom, top, near, far
+    pub fn from_planes(planes: [Plane<S>; 6]) -> Frustum<S> {
+        Frustum::new(planes[0], planes[1], planes[2], planes[3], planes[4], planes[5])
+    }
+
+    //Gribb-Hartmann plane extraction, m is a (view-)projection matrix that maps to clip space as m * (p, 1),
+    //so the planes come out in the space that m transforms from (world space for a view-projection matrix),
+    //the depth range has to match the one the projection was built for, infinite projections give a frustum without a far plane,
+    //for reversed-Z perspective projections the lowest depth value is the far plane, so the two depth planes get swapped back,
+    //(reversed-Z orthographic projections have no w to tell them apart, their near and far planes stay swapped)
+    pub fn from_view_projection_mat(m: Mat4<S>, depth: DepthRange) -> Frustum<S> {
+        let (low_depth, high_depth) = match depth {
+            DepthRange::NegOneToOne => (m.r3 + m.r2, m.r3 - m.r2),
+            DepthRange::ZeroToOne => (m.r2, m.r3 - m.r2),
+        };
+        //The near plane faces away from the eye, along the direction in which w grows
+        let forward = Vec3 { x: m.r3.x, y: m.r3.y, z: m.r3.z };
+        let reversed = Frustum::is_at_infinity(low_depth) ||
+            (!Frustum::is_at_infinity(high_depth) && Vec3 { x: low_depth.x, y: low_depth.y, z: low_depth.z }.dot(forward) < S::zero());
+        let (near, far) = if reversed { (high_depth, low_depth) } else { (low_depth, high_depth) };
+        Frustum {
+            left: Frustum::get_clip_plane(m.r3 + m.r0),
+            right: Frustum::get_clip_plane(m.r3 - m.r0),
+            bottom: Frustum::get_clip_plane(m.r3 + m.r1),
+            top: Frustum::get_clip_plane(m.r3 - m.r1),
+            near: Frustum::get_clip_plane(near),
+            far: if Frustum::is_at_infinity(far) { None } else { Some(Frustum::get_clip_plane(far)) },
+        }
+    }
+
+    //The planes in the order left, right, bottom, top, near, far, without the far plane if there is none
+    pub fn get_planes(&self) -> impl Iterator<Item = &Plane<S>> {
+        core::iter::once(&self.left)
+            .chain(core::iter::once(&self.right))
+            .chain(core::iter::once(&self.bottom))
+            .chain(core::iter::once(&self.top))
+            .chain(core::iter::once(&self.near))
+            .chain(self.far.as_ref())
+    }
+
+    //Ordered by the bits of the index, bit 0 picks right over left, bit 1 top over bottom and bit 2 far over near,
+    //None if the planes do not meet in a single point (parallel side planes for example) or there is no far plane
+    pub fn corners(&self) -> Option<[Vec3<S>; 8]> {
+        let far = self.far.as_ref()?;
+        let mut corners = [Vec3::zero(); 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let x = if i & 1 != 0 { &self.right } else { &self.left };
+            let y = if i & 2 != 0 { &self.top } else { &self.bottom };
+            let z = if i & 4 != 0 { far } else { &self.near };
+            *corner = Plane::intersect_planes(x, y, z)?;
+        }
+        Some(corners)
+    }
+
+    pub fn transformed(&self, m: Mat4<S>) -> Frustum<S> {
+        Frustum {
+            left: self.left.transformed(m),
+            right: self.right.transformed(m),
+            bottom: self.bottom.transformed(m),
+            top: self.top.transformed(m),
+            near: self.near.transformed(m),
+            far: self.far.map(|far| far.transformed(m)),
+        }
+    }
+
+    pub fn contains_point(&self, p: Vec3<S>) -> bool {
+        self.get_planes().all(|plane| plane.signed_distance(p) >= S::zero())
+    }
+
+    //Points inside the frustum are their own closest point, otherwise it is the closest of the projections onto the faces
+    //that land on the frustum and the closest points on the twelve edges
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        if self.contains_point(p) {
+            return p;
+        }
+
+        let tolerance: S = num::cast(1e-4).unwrap();
+        let mut closest = p;
+        let mut closest_dist_sq = S::infinity();
+        let mut consider = |candidate: Vec3<S>| {
+            let dist_sq = (candidate - p).length_squared();
+            if dist_sq < closest_dist_sq {
+                closest = candidate;
+                closest_dist_sq = dist_sq;
+            }
+        };
+
+        for (i, plane) in self.get_planes().enumerate() {
+            let projected = plane.closest_point(p);
+            let on_face = self.get_planes().enumerate()
+                .all(|(j, other)| i == j || other.signed_distance(projected) >= -tolerance);
+            if on_face {
+                consider(projected);
+            }
+        }
+
+        if let Some(corners) = self.corners() {
+            for i in 0..8 {
+                for axis in 0..3 {
+                    let j = i | (1 << axis);
+                    if i != j {
+                        consider(LineSegment::new(corners[i], corners[j]).closest_point(p));
+                    }
+                }
+            }
+        }
+
+        closest
+    }
+
+    //Conservative, spheres near the edges of the frustum can be reported as intersecting while being just outside,
+    //use overlaps_sphere when that matters
+    pub fn classify_sphere(&self, sphere: &Sphere<S>) -> Containment {
+        self.classify(sphere.center, |_| sphere.radius)
+    }
+
+    //Conservative in the same way as classify_sphere
+    pub fn classify_aabb(&self, aabb: &Aabb<S>) -> Containment {
+        let half_extents = aabb.half_extents();
+        self.classify(aabb.center(), |normal| {
+            half_extents.x * normal.x.abs() + half_extents.y * normal.y.abs() + half_extents.z * normal.z.abs()
+        })
+    }
+
+    //Negative inside (the distance to the nearest plane), positive outside
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        if self.contains_point(p) {
+            self.get_planes().fold(S::infinity(), |dist, plane| dist.min(plane.signed_distance(p))) * -S::one()
+        } else {
+            (self.closest_point(p) - p).length()
+        }
+    }
+
+    //radius gives the extent of the volume along the normal of a plane
+    fn classify<F>(&self, center: Vec3<S>, radius: F) -> Containment where F: Fn(Vec3<S>) -> S {
+        let mut containment = Containment::Inside;
+        for plane in self.get_planes() {
+            let dist = plane.signed_distance(center);
+            let r = radius(plane.normal);
+            if dist < -r {
+                return Containment::Outside;
+            } else if dist < r {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    //The clip space inequality row.dot(p, 1) >= 0 as a plane
+    fn get_clip_plane(row: Vec4<S>) -> Plane<S> {
+        Plane::new(Vec3 { x: row.x, y: row.y, z: row.z }, -row.w)
+    }
+
+    //A row without an xyz part (the far plane of an infinite projection) holds for any point, so it is no plane at all
+    fn is_at_infinity(row: Vec4<S>) -> bool {
+        Vec3 { x: row.x, y: row.y, z: row.z }.length_squared() <= S::DEF_EPSILON * S::DEF_EPSILON
+    }
+}
@@ -0,0 +1,290 @@
+//Ray casts and overlap tests between the primitives
+
+use super::*;
+
+//Where a ray hit something, the normal is that of the surface that was hit and always faces back against the ray,
+//rays that start inside a solid hit it straight away (t == 0), with the normal pointing back along the ray
+#[derive(Copy, Clone, Debug)]
+pub struct RayHit<S> {
+    pub t: S,
+    pub point: Vec3<S>,
+    pub normal: Vec3<S>,
+}
+
+impl<S> RayHit<S> where S: FloatType<S> {
+    fn new(ray: &Ray<S>, t: S, normal: Vec3<S>) -> RayHit<S> {
+        RayHit {
+            t,
+            point: ray.at(t),
+            normal,
+        }
+    }
+
+    fn inside(ray: &Ray<S>) -> RayHit<S> {
+        RayHit::new(ray, S::zero(), -ray.direction)
+    }
+}
+
+impl<S> Ray<S> where S: FloatType<S> {
+    //Both sides of the plane can be hit, rays running parallel to the plane never hit it
+    pub fn intersect_plane(&self, plane: &Plane<S>) -> Option<RayHit<S>> {
+        let denom = plane.normal.dot(self.direction);
+        if denom.abs() <= S::DEF_EPSILON {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < S::zero() {
+            return None;
+        }
+        let normal = if denom > S::zero() { -plane.normal } else { plane.normal };
+        Some(RayHit::new(self, t, normal))
+    }
+
+    pub fn intersect_sphere(&self, sphere: &Sphere<S>) -> Option<RayHit<S>> {
+        let m = self.origin - sphere.center;
+        let c = m.dot(m) - sphere.radius * sphere.radius;
+        if c <= S::zero() {
+            return Some(RayHit::inside(self));
+        }
+        let b = m.dot(self.direction);
+        if b > S::zero() {
+            return None;
+        }
+        let discriminant = b * b - c;
+        if discriminant < S::zero() {
+            return None;
+        }
+        let t = -b - discriminant.sqrt();
+        let point = self.at(t);
+        Some(RayHit {
+            t,
+            point,
+            normal: (point - sphere.center) / sphere.radius,
+        })
+    }
+
+    pub fn intersect_aabb(&self, aabb: &Aabb<S>) -> Option<RayHit<S>> {
+        let (t, normal) = intersect_local_box(self.origin - aabb.center(), self.direction, aabb.half_extents())?;
+        Some(RayHit::new(self, t, normal.unwrap_or(-self.direction)))
+    }
+
+    pub fn intersect_obb(&self, obb: &Obb<S>) -> Option<RayHit<S>> {
+        let (t, normal) = intersect_local_box(obb.to_local(self.origin), obb.axes * self.direction, obb.half_extents)?;
+        let normal = match normal {
+            Some(normal) => obb.axes.transpose() * normal,
+            None => -self.direction,
+        };
+        Some(RayHit::new(self, t, normal))
+    }
+
+    //Möller–Trumbore, both sides of the triangle can be hit
+    pub fn intersect_triangle(&self, triangle: &Triangle<S>) -> Option<RayHit<S>> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        if det.abs() <= S::DEF_EPSILON {
+            return None;
+        }
+        let inv_det = S::one() / det;
+
+        let s = self.origin - triangle.a;
+        let u = s.dot(p) * inv_det;
+        if u < S::zero() || u > S::one() {
+            return None;
+        }
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < S::zero() || u + v > S::one() {
+            return None;
+        }
+        let t = edge2.dot(q) * inv_det;
+        if t < S::zero() {
+            return None;
+        }
+
+        let normal = edge1.cross(edge2).normalized();
+        let normal = if normal.dot(self.direction) > S::zero() { -normal } else { normal };
+        Some(RayHit::new(self, t, normal))
+    }
+}
+
+impl<S> Sphere<S> where S: FloatType<S> {
+    pub fn overlaps_sphere(&self, other: &Sphere<S>) -> bool {
+        let radii = self.radius + other.radius;
+        (self.center - other.center).length_squared() <= radii * radii
+    }
+
+    pub fn overlaps_aabb(&self, aabb: &Aabb<S>) -> bool {
+        (aabb.closest_point(self.center) - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    pub fn overlaps_obb(&self, obb: &Obb<S>) -> bool {
+        (obb.closest_point(self.center) - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    //Exact, this uses the distance to the frustum itself rather than to its planes
+    pub fn overlaps_frustum(&self, frustum: &Frustum<S>) -> bool {
+        frustum.signed_distance(self.center) <= self.radius
+    }
+}
+
+impl<S> Aabb<S> where S: FloatType<S> {
+    pub fn overlaps_aabb(&self, other: &Aabb<S>) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+            self.min.y <= other.max.y && self.max.y >= other.min.y &&
+            self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+
+    pub fn overlaps_sphere(&self, sphere: &Sphere<S>) -> bool {
+        sphere.overlaps_aabb(self)
+    }
+
+    pub fn overlaps_obb(&self, obb: &Obb<S>) -> bool {
+        Obb::from_aabb(self).overlaps_obb(obb)
+    }
+
+    pub fn overlaps_frustum(&self, frustum: &Frustum<S>) -> bool {
+        Obb::from_aabb(self).overlaps_frustum(frustum)
+    }
+}
+
+impl<S> Obb<S> where S: FloatType<S> {
+    //Separating axis test, Real-Time Collision Detection, Ericson, 4.4.1
+    pub fn overlaps_obb(&self, other: &Obb<S>) -> bool {
+        //Keeps the cross products of (nearly) parallel axes from giving false separations
+        let epsilon: S = num::cast(1e-6).unwrap();
+        let a = self.half_extents;
+        let b = other.half_extents;
+
+        let mut r = Mat3::empty();
+        let mut abs_r = Mat3::empty();
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = self.axes[i].dot(other.axes[j]);
+                abs_r[i][j] = r[i][j].abs() + epsilon;
+            }
+        }
+        let offset = other.center - self.center;
+        let t = Vec3 { x: offset.dot(self.axes[0]), y: offset.dot(self.axes[1]), z: offset.dot(self.axes[2]) };
+
+        for i in 0..3 {
+            let ra = a[i];
+            let rb = b[0] * abs_r[i][0] + b[1] * abs_r[i][1] + b[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+
+        for j in 0..3 {
+            let ra = a[0] * abs_r[0][j] + a[1] * abs_r[1][j] + a[2] * abs_r[2][j];
+            let rb = b[j];
+            if (t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j]).abs() > ra + rb {
+                return false;
+            }
+        }
+
+        //The cross products of the axes of both boxes, axis i of self with axis j of other
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = a[i1] * abs_r[i2][j] + a[i2] * abs_r[i1][j];
+                let rb = b[j1] * abs_r[i][j2] + b[j2] * abs_r[i][j1];
+                if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn overlaps_sphere(&self, sphere: &Sphere<S>) -> bool {
+        sphere.overlaps_obb(self)
+    }
+
+    pub fn overlaps_aabb(&self, aabb: &Aabb<S>) -> bool {
+        self.overlaps_obb(&Obb::from_aabb(aabb))
+    }
+
+    //Separating axis test on the planes of the frustum and the faces of the box, boxes that are only separated
+    //along the cross product of two edges are reported as overlapping, which is what culling wants anyway
+    pub fn overlaps_frustum(&self, frustum: &Frustum<S>) -> bool {
+        for plane in frustum.get_planes() {
+            let radius = self.half_extents.x * plane.normal.dot(self.axes[0]).abs() +
+                self.half_extents.y * plane.normal.dot(self.axes[1]).abs() +
+                self.half_extents.z * plane.normal.dot(self.axes[2]).abs();
+            if plane.signed_distance(self.center) < -radius {
+                return false;
+            }
+        }
+
+        if let Some(corners) = frustum.corners() {
+            for i in 0..3 {
+                let axis = self.axes[i];
+                let center = axis.dot(self.center);
+                let (min, max) = corners.iter().fold((S::infinity(), S::neg_infinity()), |(min, max), c| {
+                    let d = axis.dot(*c);
+                    (min.min(d), max.max(d))
+                });
+                if min > center + self.half_extents[i] || max < center - self.half_extents[i] {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<S> Frustum<S> where S: FloatType<S> {
+    pub fn overlaps_sphere(&self, sphere: &Sphere<S>) -> bool {
+        sphere.overlaps_frustum(self)
+    }
+
+    pub fn overlaps_aabb(&self, aabb: &Aabb<S>) -> bool {
+        aabb.overlaps_frustum(self)
+    }
+
+    pub fn overlaps_obb(&self, obb: &Obb<S>) -> bool {
+        obb.overlaps_frustum(self)
+    }
+}
+
+//Slab test against a box centered at the origin, the normal is None when the ray starts inside the box
+fn intersect_local_box<S>(origin: Vec3<S>, direction: Vec3<S>, half_extents: Vec3<S>) -> Option<(S, Option<Vec3<S>>)> where S: FloatType<S> {
+    let mut t_min = S::zero();
+    let mut t_max = S::infinity();
+    let mut hit_axis = None;
+
+    for i in 0..3 {
+        if direction[i].abs() <= S::DEF_EPSILON {
+            if origin[i].abs() > half_extents[i] {
+                return None;
+            }
+            continue;
+        }
+        let inv_d = S::one() / direction[i];
+        let mut t1 = (-half_extents[i] - origin[i]) * inv_d;
+        let mut t2 = (half_extents[i] - origin[i]) * inv_d;
+        if t1 > t2 {
+            core::mem::swap(&mut t1, &mut t2);
+        }
+        if t1 > t_min {
+            t_min = t1;
+            hit_axis = Some(i);
+        }
+        t_max = t_max.min(t2);
+        if t_min > t_max {
+            return None;
+        }
+    }
+
+    let normal = hit_axis.map(|i| {
+        let mut normal = Vec3::zero();
+        normal[i] = if direction[i] > S::zero() { -S::one() } else { S::one() };
+        normal
+    });
+    Some((t_min, normal))
+}
@@ -0,0 +1,99 @@
+//Geometric primitives for physics and culling, all of them work in the same space as the rest of straal,
+//so points get transformed as Mat4 * (p, 1) and directions as Mat4 * (v, 0)
+
+use super::*;
+
+pub use self::aabb::*;
+pub use self::capsule::*;
+pub use self::frustum::*;
+pub use self::intersection::*;
+pub use self::obb::*;
+pub use self::plane::*;
+pub use self::ray::*;
+pub use self::segment::*;
+pub use self::sphere::*;
+pub use self::triangle::*;
+
+pub mod aabb;
+pub mod capsule;
+pub mod frustum;
+pub mod intersection;
+pub mod obb;
+pub mod plane;
+pub mod ray;
+pub mod segment;
+pub mod sphere;
+pub mod triangle;
+
+pub type Rayn = Ray<f32>;
+pub type Rayh = Ray<f64>;
+
+pub type LineSegmentn = LineSegment<f32>;
+pub type LineSegmenth = LineSegment<f64>;
+
+pub type Planen = Plane<f32>;
+pub type Planeh = Plane<f64>;
+
+pub type Spheren = Sphere<f32>;
+pub type Sphereh = Sphere<f64>;
+
+pub type Aabbn = Aabb<f32>;
+pub type Aabbh = Aabb<f64>;
+
+pub type Obbn = Obb<f32>;
+pub type Obbh = Obb<f64>;
+
+pub type Capsulen = Capsule<f32>;
+pub type Capsuleh = Capsule<f64>;
+
+pub type Trianglen = Triangle<f32>;
+pub type Triangleh = Triangle<f64>;
+
+pub type Frustumn = Frustum<f32>;
+pub type Frustumh = Frustum<f64>;
+
+pub type RayHitn = RayHit<f32>;
+pub type RayHith = RayHit<f64>;
+
+//Transforms a point, including the perspective divide
+pub(crate) fn transform_point<S>(m: Mat4<S>, p: Vec3<S>) -> Vec3<S> where S: FloatType<S> {
+    let v = m * Vec4 { x: p.x, y: p.y, z: p.z, w: S::one() };
+    Vec3 { x: v.x, y: v.y, z: v.z } / v.w
+}
+
+pub(crate) fn transform_vector<S>(m: Mat4<S>, v: Vec3<S>) -> Vec3<S> where S: FloatType<S> {
+    Mat3::from(m) * v
+}
+
+//Normals need the inverse transpose to stay perpendicular under non-uniform scaling
+pub(crate) fn transform_normal<S>(m: Mat4<S>, n: Vec3<S>) -> Vec3<S> where S: FloatType<S> {
+    (Mat3::from(m).inverse().transpose() * n).normalized()
+}
+
+//The biggest factor that the matrix scales lengths by along any of the axes
+pub(crate) fn get_max_scale<S>(m: Mat4<S>) -> S where S: FloatType<S> {
+    let x = Vec3 { x: m[0][0], y: m[1][0], z: m[2][0] }.length_squared();
+    let y = Vec3 { x: m[0][1], y: m[1][1], z: m[2][1] }.length_squared();
+    let z = Vec3 { x: m[0][2], y: m[1][2], z: m[2][2] }.length_squared();
+    x.max(y).max(z).sqrt()
+}
+
+//Signed distance to a box centered at the origin, p and the half extents are in the local space of the box
+pub(crate) fn box_signed_distance<S>(p: Vec3<S>, half_extents: Vec3<S>) -> S where S: FloatType<S> {
+    let q = Vec3 {
+        x: p.x.abs() - half_extents.x,
+        y: p.y.abs() - half_extents.y,
+        z: p.z.abs() - half_extents.z,
+    };
+    let outside = Vec3 { x: q.x.max(S::zero()), y: q.y.max(S::zero()), z: q.z.max(S::zero()) }.length();
+    let inside = q.x.max(q.y).max(q.z).min(S::zero());
+    outside + inside
+}
+
+pub(crate) fn component_min<S>(a: Vec3<S>, b: Vec3<S>) -> Vec3<S> where S: FloatType<S> {
+    Vec3 { x: a.x.min(b.x), y: a.y.min(b.y), z: a.z.min(b.z) }
+}
+
+pub(crate) fn component_max<S>(a: Vec3<S>, b: Vec3<S>) -> Vec3<S> where S: FloatType<S> {
+    Vec3 { x: a.x.max(b.x), y: a.y.max(b.y), z: a.z.max(b.z) }
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+//Oriented bounding box, the rows of axes are the unit local x, y and z axes of the box in world space
+#[derive(Copy, Clone, Debug)]
+pub struct Obb<S> {
+    pub center: Vec3<S>,
+    pub axes: Mat3<S>,
+    pub half_extents: Vec3<S>,
+}
+
+impl<S> Obb<S> where S: FloatType<S> {
+    pub fn new(center: Vec3<S>, axes: Mat3<S>, half_extents: Vec3<S>) -> Obb<S> {
+        Obb { center, axes, half_extents }
+    }
+
+    //The box gets rotated by the quaternion, so its local axes are q * right, q * up and q * forward
+    pub fn from_rotation(center: Vec3<S>, rotation: Quat<S>, half_extents: Vec3<S>) -> Obb<S> {
+        Obb {
+            center,
+            axes: Mat3::new_from_vec3s(rotation * Vec3::right(), rotation * Vec3::up(), rotation * Vec3::forward()),
+            half_extents,
+        }
+    }
+
+    pub fn from_aabb(aabb: &Aabb<S>) -> Obb<S> {
+        Obb::new(aabb.center(), Mat3::identity(), aabb.half_extents())
+    }
+
+    pub fn corners(&self) -> [Vec3<S>; 8] {
+        let mut corners = [self.center; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            for axis in 0..3 {
+                let sign = if i & (1 << axis) != 0 { S::one() } else { -S::one() };
+                *corner += self.axes[axis] * (self.half_extents[axis] * sign);
+            }
+        }
+        corners
+    }
+
+    //Scaling is moved into the half extents, shearing transforms do not give an exact box
+    pub fn transformed(&self, m: Mat4<S>) -> Obb<S> {
+        let mut axes = self.axes;
+        let mut half_extents = self.half_extents;
+        for i in 0..3 {
+            let axis = transform_vector(m, self.axes[i]);
+            let len = axis.length();
+            axes[i] = axis / len;
+            half_extents[i] = half_extents[i] * len;
+        }
+        Obb::new(transform_point(m, self.center), axes, half_extents)
+    }
+
+    //The point in the local space of the box, where the box is centered at the origin and axis aligned
+    pub fn to_local(&self, p: Vec3<S>) -> Vec3<S> {
+        self.axes * (p - self.center)
+    }
+
+    pub fn contains_point(&self, p: Vec3<S>) -> bool {
+        let local = self.to_local(p);
+        local.x.abs() <= self.half_extents.x &&
+            local.y.abs() <= self.half_extents.y &&
+            local.z.abs() <= self.half_extents.z
+    }
+
+    //Points inside the box are their own closest point
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        let local = self.to_local(p);
+        let mut closest = self.center;
+        for i in 0..3 {
+            let dist = local[i].max(-self.half_extents[i]).min(self.half_extents[i]);
+            closest += self.axes[i] * dist;
+        }
+        closest
+    }
+
+    //Negative inside, positive outside
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        box_signed_distance(self.to_local(p), self.half_extents)
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+//Plane with a unit normal, the points on it satisfy normal.dot(p) == d, the normal points towards the positive side
+#[derive(Copy, Clone, Debug)]
+pub struct Plane<S> {
+    pub normal: Vec3<S>,
+    pub d: S,
+}
+
+impl<S> Plane<S> where S: FloatType<S> {
+    //The normal does not have to be normalized, d gets scaled along with it
+    pub fn new(normal: Vec3<S>, d: S) -> Plane<S> {
+        let inv_len = S::one() / normal.length();
+        Plane {
+            normal: normal * inv_len,
+            d: d * inv_len,
+        }
+    }
+
+    pub fn from_point_normal(point: Vec3<S>, normal: Vec3<S>) -> Plane<S> {
+        let normal = normal.normalized();
+        Plane {
+            normal,
+            d: normal.dot(point),
+        }
+    }
+
+    //The normal points to the side from which a, b and c are seen counter-clockwise
+    pub fn from_points(a: Vec3<S>, b: Vec3<S>, c: Vec3<S>) -> Plane<S> {
+        Plane::from_point_normal(a, (b - a).cross(c - a))
+    }
+
+    pub fn flipped(&self) -> Plane<S> {
+        Plane {
+            normal: -self.normal,
+            d: -self.d,
+        }
+    }
+
+    //Any point on the plane, the one closest to the origin
+    pub fn origin(&self) -> Vec3<S> {
+        self.normal * self.d
+    }
+
+    pub fn transformed(&self, m: Mat4<S>) -> Plane<S> {
+        Plane::from_point_normal(transform_point(m, self.origin()), transform_normal(m, self.normal))
+    }
+
+    //Positive in front of the plane (the side the normal points to), negative behind it
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        self.normal.dot(p) - self.d
+    }
+
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    //The point where three planes meet, None if two or more of them are parallel
+    pub fn intersect_planes(a: &Plane<S>, b: &Plane<S>, c: &Plane<S>) -> Option<Vec3<S>> {
+        let bc = b.normal.cross(c.normal);
+        let denom = a.normal.dot(bc);
+        if denom.abs() <= S::DEF_EPSILON {
+            return None;
+        }
+        let ca = c.normal.cross(a.normal);
+        let ab = a.normal.cross(b.normal);
+        Some((bc * a.d + ca * b.d + ab * c.d) / denom)
+    }
+}
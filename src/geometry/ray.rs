@@ -0,0 +1,43 @@
+use super::*;
+
+//Half-line starting at the origin, the direction is kept normalized so t is the distance along the ray
+#[derive(Copy, Clone, Debug)]
+pub struct Ray<S> {
+    pub origin: Vec3<S>,
+    pub direction: Vec3<S>,
+}
+
+impl<S> Ray<S> where S: FloatType<S> {
+    pub fn new(origin: Vec3<S>, direction: Vec3<S>) -> Ray<S> {
+        Ray {
+            origin,
+            direction: direction.normalized(),
+        }
+    }
+
+    pub fn from_points(from: Vec3<S>, towards: Vec3<S>) -> Ray<S> {
+        Ray::new(from, towards - from)
+    }
+
+    pub fn at(&self, t: S) -> Vec3<S> {
+        self.origin + self.direction * t
+    }
+
+    pub fn transformed(&self, m: Mat4<S>) -> Ray<S> {
+        Ray::new(transform_point(m, self.origin), transform_vector(m, self.direction))
+    }
+
+    //Distance along the ray to the point on it that is closest to p
+    pub fn closest_t(&self, p: Vec3<S>) -> S {
+        (p - self.origin).dot(self.direction).max(S::zero())
+    }
+
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        self.at(self.closest_t(p))
+    }
+
+    //A ray has no inside, so this is never negative
+    pub fn distance(&self, p: Vec3<S>) -> S {
+        (p - self.closest_point(p)).length()
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+#[derive(Copy, Clone, Debug)]
+pub struct LineSegment<S> {
+    pub start: Vec3<S>,
+    pub end: Vec3<S>,
+}
+
+impl<S> LineSegment<S> where S: FloatType<S> {
+    pub fn new(start: Vec3<S>, end: Vec3<S>) -> LineSegment<S> {
+        LineSegment { start, end }
+    }
+
+    //Goes from the center minus half the direction to the center plus half the direction
+    pub fn from_center(center: Vec3<S>, half_direction: Vec3<S>) -> LineSegment<S> {
+        LineSegment {
+            start: center - half_direction,
+            end: center + half_direction,
+        }
+    }
+
+    //Not normalized, start + direction() == end
+    pub fn direction(&self) -> Vec3<S> {
+        self.end - self.start
+    }
+
+    pub fn length(&self) -> S {
+        self.direction().length()
+    }
+
+    pub fn midpoint(&self) -> Vec3<S> {
+        let half: S = num::cast(0.5).unwrap();
+        (self.start + self.end) * half
+    }
+
+    //t goes from 0 at the start to 1 at the end
+    pub fn at(&self, t: S) -> Vec3<S> {
+        self.start + self.direction() * t
+    }
+
+    pub fn transformed(&self, m: Mat4<S>) -> LineSegment<S> {
+        LineSegment {
+            start: transform_point(m, self.start),
+            end: transform_point(m, self.end),
+        }
+    }
+
+    pub fn closest_t(&self, p: Vec3<S>) -> S {
+        let dir = self.direction();
+        let len_sq = dir.length_squared();
+        if len_sq <= S::zero() {
+            return S::zero();
+        }
+        ((p - self.start).dot(dir) / len_sq).max(S::zero()).min(S::one())
+    }
+
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        self.at(self.closest_t(p))
+    }
+
+    //A segment has no inside, so this is never negative
+    pub fn distance(&self, p: Vec3<S>) -> S {
+        (p - self.closest_point(p)).length()
+    }
+
+    //Closest points between two segments, the first one on self and the second one on other
+    //(Real-Time Collision Detection, Ericson, 5.1.9)
+    pub fn closest_points_to_segment(&self, other: &LineSegment<S>) -> (Vec3<S>, Vec3<S>) {
+        let d1 = self.direction();
+        let d2 = other.direction();
+        let r = self.start - other.start;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(r);
+        let clamp01 = |x: S| x.max(S::zero()).min(S::one());
+
+        let (s, t) = if a <= S::DEF_EPSILON && e <= S::DEF_EPSILON {
+            (S::zero(), S::zero())
+        } else if a <= S::DEF_EPSILON {
+            (S::zero(), clamp01(f / e))
+        } else {
+            let c = d1.dot(r);
+            if e <= S::DEF_EPSILON {
+                (clamp01(-c / a), S::zero())
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                let s = if denom != S::zero() { clamp01((b * f - c * e) / denom) } else { S::zero() };
+                let t = (b * s + f) / e;
+                if t < S::zero() {
+                    (clamp01(-c / a), S::zero())
+                } else if t > S::one() {
+                    (clamp01((b - c) / a), S::one())
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        (self.at(s), other.at(t))
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Sphere<S> {
+    pub center: Vec3<S>,
+    pub radius: S,
+}
+
+impl<S> Sphere<S> where S: FloatType<S> {
+    pub fn new(center: Vec3<S>, radius: S) -> Sphere<S> {
+        Sphere { center, radius }
+    }
+
+    //Bounding sphere of a set of points using Ritter's method, this is not the minimal one, but it is close enough and fast
+    pub fn from_points(points: &[Vec3<S>]) -> Sphere<S> {
+        if points.is_empty() {
+            return Sphere::new(Vec3::zero(), S::zero());
+        }
+
+        let farthest_from = |from: Vec3<S>| {
+            points.iter().fold(points[0], |best, &p| {
+                if (p - from).length_squared() > (best - from).length_squared() { p } else { best }
+            })
+        };
+        let a = farthest_from(points[0]);
+        let b = farthest_from(a);
+
+        let half: S = num::cast(0.5).unwrap();
+        let mut sphere = Sphere::new((a + b) * half, (b - a).length() * half);
+        for &p in points {
+            let dist = (p - sphere.center).length();
+            if dist > sphere.radius {
+                let new_radius = (sphere.radius + dist) * half;
+                sphere.center += (p - sphere.center) * ((new_radius - sphere.radius) / dist);
+                sphere.radius = new_radius;
+            }
+        }
+        sphere
+    }
+
+    pub fn from_aabb(aabb: &Aabb<S>) -> Sphere<S> {
+        Sphere::new(aabb.center(), aabb.half_extents().length())
+    }
+
+    //Non-uniform scaling turns a sphere into an ellipsoid, so the radius is scaled by the largest factor to keep it enclosed
+    pub fn transformed(&self, m: Mat4<S>) -> Sphere<S> {
+        Sphere::new(transform_point(m, self.center), self.radius * get_max_scale(m))
+    }
+
+    pub fn contains_point(&self, p: Vec3<S>) -> bool {
+        (p - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    //Points inside the sphere are their own closest point
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        let offset = p - self.center;
+        let dist = offset.length();
+        if dist <= self.radius {
+            p
+        } else {
+            self.center + offset * (self.radius / dist)
+        }
+    }
+
+    //Negative inside, positive outside
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        (p - self.center).length() - self.radius
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Triangle<S> {
+    pub a: Vec3<S>,
+    pub b: Vec3<S>,
+    pub c: Vec3<S>,
+}
+
+impl<S> Triangle<S> where S: FloatType<S> {
+    pub fn new(a: Vec3<S>, b: Vec3<S>, c: Vec3<S>) -> Triangle<S> {
+        Triangle { a, b, c }
+    }
+
+    //Points to the side from which a, b and c are seen counter-clockwise
+    pub fn normal(&self) -> Vec3<S> {
+        (self.b - self.a).cross(self.c - self.a).normalized()
+    }
+
+    pub fn area(&self) -> S {
+        (self.b - self.a).cross(self.c - self.a).length() * num::cast(0.5).unwrap()
+    }
+
+    pub fn centroid(&self) -> Vec3<S> {
+        let three: S = num::cast(3).unwrap();
+        (self.a + self.b + self.c) / three
+    }
+
+    pub fn get_plane(&self) -> Plane<S> {
+        Plane::from_points(self.a, self.b, self.c)
+    }
+
+    //Barycentric coordinates (u, v, w) of p projected onto the plane of the triangle, so that p == u * a + v * b + w * c
+    pub fn barycentric(&self, p: Vec3<S>) -> Vec3<S> {
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = p - self.a;
+        let d00 = v0.dot(v0);
+        let d01 = v0.dot(v1);
+        let d11 = v1.dot(v1);
+        let d20 = v2.dot(v0);
+        let d21 = v2.dot(v1);
+        let denom = d00 * d11 - d01 * d01;
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Vec3 { x: S::one() - v - w, y: v, z: w }
+    }
+
+    pub fn transformed(&self, m: Mat4<S>) -> Triangle<S> {
+        Triangle {
+            a: transform_point(m, self.a),
+            b: transform_point(m, self.b),
+            c: transform_point(m, self.c),
+        }
+    }
+
+    //Real-Time Collision Detection, Ericson, 5.1.5
+    pub fn closest_point(&self, p: Vec3<S>) -> Vec3<S> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+
+        let ap = p - self.a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= S::zero() && d2 <= S::zero() {
+            return self.a;
+        }
+
+        let bp = p - self.b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= S::zero() && d4 <= d3 {
+            return self.b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= S::zero() && d1 >= S::zero() && d3 <= S::zero() {
+            return self.a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = p - self.c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= S::zero() && d5 <= d6 {
+            return self.c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= S::zero() && d2 >= S::zero() && d6 <= S::zero() {
+            return self.a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= S::zero() && (d4 - d3) >= S::zero() && (d5 - d6) >= S::zero() {
+            return self.b + (self.c - self.b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denom = S::one() / (va + vb + vc);
+        self.a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    //Distance to the closest point on the triangle, positive in front of it (the side the normal points to), negative behind it
+    pub fn signed_distance(&self, p: Vec3<S>) -> S {
+        let dist = (p - self.closest_point(p)).length();
+        if self.get_plane().signed_distance(p) < S::zero() {
+            -dist
+        } else {
+            dist
+        }
+    }
+}
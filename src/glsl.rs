@@ -0,0 +1,262 @@
+//The GLSL common and geometric built-in functions, these work on scalars as well as component-wise on Vec2-4,
+//and follow the definitions in the GLSL specification, so shader code can be ported over (and checked) as is
+
+use super::*;
+
+//Anything that GLSL calls a genType, a float or a float vector
+pub trait GenType<S>: Copy where S: FloatType<S> {
+    fn splat(s: S) -> Self;
+    fn map<F>(self, f: F) -> Self where F: Fn(S) -> S;
+    fn zip<F>(self, rhs: Self, f: F) -> Self where F: Fn(S, S) -> S;
+    fn zip3<F>(self, b: Self, c: Self, f: F) -> Self where F: Fn(S, S, S) -> S;
+    fn gen_dot(self, rhs: Self) -> S;
+}
+
+//Arguments that GLSL allows to be either the same genType or a single float (like the edges of clamp or the a of mix)
+pub trait GenArg<S, T> where S: FloatType<S>, T: GenType<S> {
+    fn into_gen(self) -> T;
+}
+
+impl<S> GenType<S> for S where S: FloatType<S> {
+    fn splat(s: S) -> S {
+        s
+    }
+
+    fn map<F>(self, f: F) -> S where F: Fn(S) -> S {
+        f(self)
+    }
+
+    fn zip<F>(self, rhs: S, f: F) -> S where F: Fn(S, S) -> S {
+        f(self, rhs)
+    }
+
+    fn zip3<F>(self, b: S, c: S, f: F) -> S where F: Fn(S, S, S) -> S {
+        f(self, b, c)
+    }
+
+    fn gen_dot(self, rhs: S) -> S {
+        self * rhs
+    }
+}
+
+impl<S> GenArg<S, S> for S where S: FloatType<S> {
+    fn into_gen(self) -> S {
+        self
+    }
+}
+
+impl<S> GenType<S> for Vec2<S> where S: FloatType<S> {
+    fn splat(s: S) -> Vec2<S> {
+        Vec2 { x: s, y: s }
+    }
+
+    fn map<F>(self, f: F) -> Vec2<S> where F: Fn(S) -> S {
+        Vec2 { x: f(self.x), y: f(self.y) }
+    }
+
+    fn zip<F>(self, rhs: Vec2<S>, f: F) -> Vec2<S> where F: Fn(S, S) -> S {
+        Vec2 { x: f(self.x, rhs.x), y: f(self.y, rhs.y) }
+    }
+
+    fn zip3<F>(self, b: Vec2<S>, c: Vec2<S>, f: F) -> Vec2<S> where F: Fn(S, S, S) -> S {
+        Vec2 { x: f(self.x, b.x, c.x), y: f(self.y, b.y, c.y) }
+    }
+
+    fn gen_dot(self, rhs: Vec2<S>) -> S {
+        self.dot(rhs)
+    }
+}
+
+impl<S> GenArg<S, Vec2<S>> for Vec2<S> where S: FloatType<S> {
+    fn into_gen(self) -> Vec2<S> {
+        self
+    }
+}
+
+impl<S> GenArg<S, Vec2<S>> for S where S: FloatType<S> {
+    fn into_gen(self) -> Vec2<S> {
+        Vec2::splat(self)
+    }
+}
+
+impl<S> GenType<S> for Vec3<S> where S: FloatType<S> {
+    fn splat(s: S) -> Vec3<S> {
+        Vec3 { x: s, y: s, z: s }
+    }
+
+    fn map<F>(self, f: F) -> Vec3<S> where F: Fn(S) -> S {
+        Vec3 { x: f(self.x), y: f(self.y), z: f(self.z) }
+    }
+
+    fn zip<F>(self, rhs: Vec3<S>, f: F) -> Vec3<S> where F: Fn(S, S) -> S {
+        Vec3 { x: f(self.x, rhs.x), y: f(self.y, rhs.y), z: f(self.z, rhs.z) }
+    }
+
+    fn zip3<F>(self, b: Vec3<S>, c: Vec3<S>, f: F) -> Vec3<S> where F: Fn(S, S, S) -> S {
+        Vec3 { x: f(self.x, b.x, c.x), y: f(self.y, b.y, c.y), z: f(self.z, b.z, c.z) }
+    }
+
+    fn gen_dot(self, rhs: Vec3<S>) -> S {
+        self.dot(rhs)
+    }
+}
+
+impl<S> GenArg<S, Vec3<S>> for Vec3<S> where S: FloatType<S> {
+    fn into_gen(self) -> Vec3<S> {
+        self
+    }
+}
+
+impl<S> GenArg<S, Vec3<S>> for S where S: FloatType<S> {
+    fn into_gen(self) -> Vec3<S> {
+        Vec3::splat(self)
+    }
+}
+
+impl<S> GenType<S> for Vec4<S> where S: FloatType<S> {
+    fn splat(s: S) -> Vec4<S> {
+        Vec4 { x: s, y: s, z: s, w: s }
+    }
+
+    fn map<F>(self, f: F) -> Vec4<S> where F: Fn(S) -> S {
+        Vec4 { x: f(self.x), y: f(self.y), z: f(self.z), w: f(self.w) }
+    }
+
+    fn zip<F>(self, rhs: Vec4<S>, f: F) -> Vec4<S> where F: Fn(S, S) -> S {
+        Vec4 { x: f(self.x, rhs.x), y: f(self.y, rhs.y), z: f(self.z, rhs.z), w: f(self.w, rhs.w) }
+    }
+
+    fn zip3<F>(self, b: Vec4<S>, c: Vec4<S>, f: F) -> Vec4<S> where F: Fn(S, S, S) -> S {
+        Vec4 { x: f(self.x, b.x, c.x), y: f(self.y, b.y, c.y), z: f(self.z, b.z, c.z), w: f(self.w, b.w, c.w) }
+    }
+
+    fn gen_dot(self, rhs: Vec4<S>) -> S {
+        self.dot(rhs)
+    }
+}
+
+impl<S> GenArg<S, Vec4<S>> for Vec4<S> where S: FloatType<S> {
+    fn into_gen(self) -> Vec4<S> {
+        self
+    }
+}
+
+impl<S> GenArg<S, Vec4<S>> for S where S: FloatType<S> {
+    fn into_gen(self) -> Vec4<S> {
+        Vec4::splat(self)
+    }
+}
+
+//Common functions
+
+pub fn abs<S, T>(x: T) -> T where S: FloatType<S>, T: GenType<S> {
+    x.map(|x| x.abs())
+}
+
+//Unlike num::Float::signum, this returns 0 for 0
+pub fn sign<S, T>(x: T) -> T where S: FloatType<S>, T: GenType<S> {
+    x.map(|x| if x > S::zero() {
+        S::one()
+    } else if x < S::zero() {
+        -S::one()
+    } else {
+        S::zero()
+    })
+}
+
+pub fn floor<S, T>(x: T) -> T where S: FloatType<S>, T: GenType<S> {
+    x.map(|x| x.floor())
+}
+
+pub fn ceil<S, T>(x: T) -> T where S: FloatType<S>, T: GenType<S> {
+    x.map(|x| x.ceil())
+}
+
+pub fn fract<S, T>(x: T) -> T where S: FloatType<S>, T: GenType<S> {
+    x.map(|x| x - x.floor())
+}
+
+//GLSL's mod, which takes the sign of y (unlike the % operator, which takes the sign of x)
+pub fn modulo<S, T, U>(x: T, y: U) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    x.zip(y.into_gen(), |x, y| x - y * (x / y).floor())
+}
+
+pub fn min<S, T, U>(x: T, y: U) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    x.zip(y.into_gen(), |x, y| if y < x { y } else { x })
+}
+
+pub fn max<S, T, U>(x: T, y: U) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    x.zip(y.into_gen(), |x, y| if x < y { y } else { x })
+}
+
+pub fn clamp<S, T, U>(x: T, min_val: U, max_val: U) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    x.zip3(min_val.into_gen(), max_val.into_gen(), |x, min_val, max_val| min(max(x, min_val), max_val))
+}
+
+pub fn mix<S, T, U>(x: T, y: T, a: U) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    x.zip3(y, a.into_gen(), |x, y, a| x * (S::one() - a) + y * a)
+}
+
+pub fn step<S, T, U>(edge: U, x: T) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    edge.into_gen().zip(x, |edge, x| if x < edge { S::zero() } else { S::one() })
+}
+
+pub fn smoothstep<S, T, U>(edge0: U, edge1: U, x: T) -> T where S: FloatType<S>, T: GenType<S>, U: GenArg<S, T> {
+    let three: S = num::cast(3).unwrap();
+    let two: S = num::cast(2).unwrap();
+    edge0.into_gen().zip3(edge1.into_gen(), x, |e0, e1, x| {
+        let t = ((x - e0) / (e1 - e0)).max(S::zero()).min(S::one());
+        t * t * (three - two * t)
+    })
+}
+
+//Geometric functions
+
+pub fn dot<S, T>(x: T, y: T) -> S where S: FloatType<S>, T: GenType<S> {
+    x.gen_dot(y)
+}
+
+pub fn length<S, T>(x: T) -> S where S: FloatType<S>, T: GenType<S> {
+    x.gen_dot(x).sqrt()
+}
+
+pub fn distance<S, T>(p0: T, p1: T) -> S where S: FloatType<S>, T: GenType<S> {
+    length(p0.zip(p1, |a, b| a - b))
+}
+
+pub fn normalize<S, T>(x: T) -> T where S: FloatType<S>, T: GenType<S> {
+    let inv_len = S::one() / length(x);
+    x.map(|x| x * inv_len)
+}
+
+pub fn cross<S>(x: Vec3<S>, y: Vec3<S>) -> Vec3<S> where S: FloatType<S> {
+    x.cross(y)
+}
+
+//Returns n if nref points against the incident vector i, -n otherwise
+pub fn faceforward<S, T>(n: T, i: T, nref: T) -> T where S: FloatType<S>, T: GenType<S> {
+    if nref.gen_dot(i) < S::zero() {
+        n
+    } else {
+        n.map(|n| -n)
+    }
+}
+
+//Reflects the incident vector i around the surface normal n, n should be normalized
+pub fn reflect<S, T>(i: T, n: T) -> T where S: FloatType<S>, T: GenType<S> {
+    let two_dot = (S::one() + S::one()) * n.gen_dot(i);
+    i.zip(n, |i, n| i - two_dot * n)
+}
+
+//Refracts the incident vector i through a surface with normal n, eta being the ratio of the indices of refraction,
+//i and n should be normalized, total internal reflection gives back a zero vector
+pub fn refract<S, T>(i: T, n: T, eta: S) -> T where S: FloatType<S>, T: GenType<S> {
+    let n_dot_i = n.gen_dot(i);
+    let k = S::one() - eta * eta * (S::one() - n_dot_i * n_dot_i);
+    if k < S::zero() {
+        T::splat(S::zero())
+    } else {
+        let n_scale = eta * n_dot_i + k.sqrt();
+        i.zip(n, |i, n| eta * i - n_scale * n)
+    }
+}
@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -275,39 +275,43 @@ impl<S> Default for IVec2<S> where S: IntegerType {
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for IVec2<i32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::IntVec2(std::mem::transmute::<IVec2<i32>, [i32; 2]>(*self))
+            glium::uniforms::UniformValue::IntVec2(core::mem::transmute::<IVec2<i32>, [i32; 2]>(*self))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for IVec2<i64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Int64Vec2(std::mem::transmute::<IVec2<i64>, [i64; 2]>(*self))
+            glium::uniforms::UniformValue::Int64Vec2(core::mem::transmute::<IVec2<i64>, [i64; 2]>(*self))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec2<i32> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::I32I32
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec2<i64> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::I64I64
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -321,39 +321,43 @@ impl<S> Default for IVec3<S> where S: IntegerType {
 }
 
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for IVec3<i32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::IntVec3(std::mem::transmute::<IVec3<i32>, [i32; 3]>(*self))
+            glium::uniforms::UniformValue::IntVec3(core::mem::transmute::<IVec3<i32>, [i32; 3]>(*self))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for IVec3<i64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Int64Vec3(std::mem::transmute::<IVec3<i64>, [i64; 3]>(*self))
+            glium::uniforms::UniformValue::Int64Vec3(core::mem::transmute::<IVec3<i64>, [i64; 3]>(*self))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for IVec3<i32> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::I32I32I32
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for IVec3<i64> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::I64I64I64
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -345,39 +345,43 @@ impl<S> Default for IVec4<S> where S: IntegerType {
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for IVec4<i32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::IntVec4(std::mem::transmute::<IVec4<i32>, [i32; 4]>(*self))
+            glium::uniforms::UniformValue::IntVec4(core::mem::transmute::<IVec4<i32>, [i32; 4]>(*self))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for IVec4<i64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Int64Vec4(std::mem::transmute::<IVec4<i64>, [i64; 4]>(*self))
+            glium::uniforms::UniformValue::Int64Vec4(core::mem::transmute::<IVec4<i64>, [i64; 4]>(*self))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for IVec4<i32> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::I32I32I32I32
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for IVec4<i64> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::I64I64I64I64
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
\ No newline at end of file
@@ -1,26 +1,60 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("straal needs either the std feature or the libm feature for its floating point functions");
+
 use core::fmt;
 
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
+#[cfg(feature = "glium")]
 pub use glium;
 pub use num;
 
+use camera::*;
+use complex::*;
+use decomposition::*;
+use dual_quat::*;
 use ivec2::*;
 use ivec3::*;
 use ivec4::*;
 use mat2::*;
 use mat3::*;
 use mat4::*;
+use non_square::*;
 use quat::*;
+use transform::*;
 use vec2::*;
 use vec3::*;
 use vec4::*;
 
+pub mod approx;
+pub mod camera;
+pub mod column_major;
+pub mod complex;
+pub mod decomposition;
+pub mod dual_quat;
+pub mod ga;
+pub mod geometry;
+pub mod glsl;
 pub mod ivec2;
 pub mod ivec3;
 pub mod ivec4;
 pub mod mat2;
 pub mod mat3;
 pub mod mat4;
+pub mod non_square;
+#[cfg(feature = "bytemuck")]
+mod pod;
 pub mod quat;
+#[cfg(feature = "serde")]
+mod serde_impls;
+//Only public so the benches and tests can compare against the scalar versions, not part of the API
+#[doc(hidden)]
+pub mod simd;
+pub mod soa;
+mod swizzle;
+pub mod transform;
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
@@ -43,9 +77,39 @@ pub type Mat3h = Mat3<f64>;
 pub type Mat4n = Mat4<f32>;
 pub type Mat4h = Mat4<f64>;
 
+pub type Mat2x3n = Mat2x3<f32>;
+pub type Mat2x3h = Mat2x3<f64>;
+
+pub type Mat2x4n = Mat2x4<f32>;
+pub type Mat2x4h = Mat2x4<f64>;
+
+pub type Mat3x2n = Mat3x2<f32>;
+pub type Mat3x2h = Mat3x2<f64>;
+
+pub type Mat3x4n = Mat3x4<f32>;
+pub type Mat3x4h = Mat3x4<f64>;
+
+pub type Mat4x2n = Mat4x2<f32>;
+pub type Mat4x2h = Mat4x2<f64>;
+
+pub type Mat4x3n = Mat4x3<f32>;
+pub type Mat4x3h = Mat4x3<f64>;
+
 pub type Quatn = Quat<f32>;
 pub type Quath = Quat<f64>;
 
+pub type Transformn = Transform<f32>;
+pub type Transformh = Transform<f64>;
+
+pub type DualQuatn = DualQuat<f32>;
+pub type DualQuath = DualQuat<f64>;
+
+pub type Decompositionn = Decomposition<f32>;
+pub type Decompositionh = Decomposition<f64>;
+
+pub type Complexn = Complex<f32>;
+pub type Complexh = Complex<f64>;
+
 pub type IVec2n = IVec2<i32>;
 pub type IVec2h = IVec2<i64>;
 
@@ -55,6 +119,12 @@ pub type IVec3h = IVec3<i64>;
 pub type IVec4n = IVec4<i32>;
 pub type IVec4h = IVec4<i64>;
 
+pub type Cameran = Camera<f32>;
+pub type Camerah = Camera<f64>;
+
+//The order of the rotations around the pitch (x), heading (y) and bank (z) axes, the first 6 are the Tait-Bryan orders,
+//which use every axis once, the last 6 are the proper Euler orders, which use the first axis again at the end (PHP is XYX, BPB is ZXZ)
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum RotationOrder {
     PHB,
     PBH,
@@ -62,16 +132,90 @@ pub enum RotationOrder {
     HBP,
     BPH,
     BHP,
+    PHP,
+    PBP,
+    HPH,
+    HBH,
+    BPB,
+    BHB,
 }
 
-pub trait FloatType<T>: num::Float + DefaultEpsilon<T> + fmt::Display {}
+impl RotationOrder {
+    //The indices of the axes (0 is x), in the order that the rotations happen
+    pub fn get_axes(self) -> [usize; 3] {
+        match self {
+            RotationOrder::PHB => [0, 1, 2],
+            RotationOrder::PBH => [0, 2, 1],
+            RotationOrder::HPB => [1, 0, 2],
+            RotationOrder::HBP => [1, 2, 0],
+            RotationOrder::BPH => [2, 0, 1],
+            RotationOrder::BHP => [2, 1, 0],
+            RotationOrder::PHP => [0, 1, 0],
+            RotationOrder::PBP => [0, 2, 0],
+            RotationOrder::HPH => [1, 0, 1],
+            RotationOrder::HBH => [1, 2, 1],
+            RotationOrder::BPB => [2, 0, 2],
+            RotationOrder::BHB => [2, 1, 2],
+        }
+    }
+
+    pub fn is_proper_euler(self) -> bool {
+        let axes = self.get_axes();
+        axes[0] == axes[2]
+    }
+
+    //The flex functions take the angles by axis, this puts them in the order of the rotations,
+    //the proper Euler orders use an axis twice, so for those pitch, heading and bank are the first, second and third angle
+    pub(crate) fn get_ordered_angles<S>(self, pitch: S, heading: S, bank: S) -> Vec3<S> where S: FloatType<S> {
+        if self.is_proper_euler() {
+            return Vec3 { x: pitch, y: heading, z: bank };
+        }
+        let by_axis = Vec3 { x: pitch, y: heading, z: bank };
+        let axes = self.get_axes();
+        Vec3 { x: by_axis[axes[0]], y: by_axis[axes[1]], z: by_axis[axes[2]] }
+    }
+}
+
+//Intrinsic rotations turn around the axes of the object, which move along with every rotation,
+//extrinsic rotations turn around the fixed axes of the parent space, intrinsic PHB is the same rotation as extrinsic BHP
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
+
+//Which way the camera looks down the z axis, left-handed looks towards +z, right-handed towards -z
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+impl Handedness {
+    //Left-handed views look down +z, right-handed ones down -z, so the view depth is z multiplied by this
+    pub(crate) fn sign<S>(self) -> S where S: FloatType<S> {
+        match self {
+            Handedness::Left => S::one(),
+            Handedness::Right => -S::one(),
+        }
+    }
+}
+
+//The range that the depth of clip space gets mapped to, OpenGL uses [-1, 1], Vulkan/D3D/Metal use [0, 1]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DepthRange {
+    NegOneToOne,
+    ZeroToOne,
+}
+
+pub trait FloatType<T>: num::Float + DefaultEpsilon<T> + fmt::Display + 'static {}
 
 pub trait IntegerType: num::Integer + num::NumCast + num::Signed + Copy + fmt::Display {}
 
 pub trait InputType: num::Num + num::NumCast + Copy + fmt::Display {}
 
 
-impl<T: num::Float + DefaultEpsilon<T> + fmt::Display> FloatType<T> for T {}
+impl<T: num::Float + DefaultEpsilon<T> + fmt::Display + 'static> FloatType<T> for T {}
 
 impl<T: num::Integer + num::NumCast + num::Signed + Copy + fmt::Display> IntegerType for T {}
 
@@ -90,10 +234,23 @@ impl DefaultEpsilon<f64> for f64 {
     const DEF_EPSILON: f64 = 1e-15;
 }
 
+//approx_eq is what PartialEq uses (with DEF_EPSILON), a relative comparison that falls back to an absolute one around zero
 pub trait ApproxEqual<S> where S: num::Float + DefaultEpsilon<S> {
     fn approx_eq(self, rhs: Self, epsilon: S) -> bool;
 }
 
+//The comparisons with an explicit kind of tolerance, kept apart from ApproxEqual so types that only implement approx_eq keep working,
+//abs_diff_eq only looks at the absolute difference, relative_eq allows a difference of max_relative times the larger magnitude,
+//and ulps_eq allows max_ulps units in the last place of the larger magnitude, the last 2 accept anything within epsilon first,
+//vectors, matrices and quaternions compare every component (see approx.rs)
+pub trait ApproxEqualExt<S>: ApproxEqual<S> where S: num::Float + DefaultEpsilon<S> {
+    fn abs_diff_eq(self, rhs: Self, epsilon: S) -> bool;
+
+    fn relative_eq(self, rhs: Self, epsilon: S, max_relative: S) -> bool;
+
+    fn ulps_eq(self, rhs: Self, epsilon: S, max_ulps: u32) -> bool;
+}
+
 impl<S> ApproxEqual<S> for S where S: num::Float + DefaultEpsilon<S> {
     fn approx_eq(self, rhs: S, epsilon: S) -> bool {
         let abs_a = self.abs();
@@ -108,3 +265,33 @@ impl<S> ApproxEqual<S> for S where S: num::Float + DefaultEpsilon<S> {
         }
     }
 }
+
+impl<S> ApproxEqualExt<S> for S where S: num::Float + DefaultEpsilon<S> {
+    fn abs_diff_eq(self, rhs: S, epsilon: S) -> bool {
+        self == rhs || (self - rhs).abs() <= epsilon
+    }
+
+    fn relative_eq(self, rhs: S, epsilon: S, max_relative: S) -> bool {
+        if self == rhs {
+            return true;
+        }
+        if self.is_infinite() || rhs.is_infinite() {
+            return false;
+        }
+        let diff = (self - rhs).abs();
+        diff <= epsilon || diff <= self.abs().max(rhs.abs()) * max_relative
+    }
+
+    fn ulps_eq(self, rhs: S, epsilon: S, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(rhs, epsilon) {
+            return true;
+        }
+        if self.is_nan() || rhs.is_nan() || self.is_infinite() || rhs.is_infinite() || self.is_sign_negative() != rhs.is_sign_negative() {
+            return false;
+        }
+        //integer_decode gives mantissa * 2^exponent, with the mantissa as a whole number, so 2^exponent is one unit in the last place
+        let (_, exponent, _) = self.abs().max(rhs.abs()).integer_decode();
+        let ulp = (S::one() + S::one()).powi(exponent as i32);
+        (self - rhs).abs() <= ulp * num::cast(max_ulps).unwrap()
+    }
+}
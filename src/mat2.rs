@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -56,10 +56,25 @@ impl<S> Mat2<S> where S: FloatType<S> {
         }
     }
 
+    //Unchecked, singular matrices give non-finite elements, use try_inverse when that can happen
     pub fn inverse(&self) -> Mat2<S> {
         self.adjoint() / self.determinant()
     }
 
+    pub fn try_inverse(&self) -> Option<Mat2<S>> {
+        self.try_inverse_with_epsilon(S::DEF_EPSILON)
+    }
+
+    //None for singular and ill-conditioned matrices, the determinant is compared to the product of the lengths of the rows
+    //(which bounds it), so the check does not depend on the scale of the matrix
+    pub fn try_inverse_with_epsilon(&self, epsilon: S) -> Option<Mat2<S>> {
+        let det = self.determinant();
+        if !det.is_finite() || det.abs() <= epsilon * self.r0.length() * self.r1.length() {
+            return None;
+        }
+        Some(self.adjoint() / det)
+    }
+
     pub fn transpose(&self) -> Mat2<S> {
         Mat2 {
             r0: Vec2 { x: self[0][0], y: self[1][0] },
@@ -111,11 +126,12 @@ impl<S> Neg for Mat2<S> where S: FloatType<S> {
     }
 }
 
+//Checked in every build, panics where try_inverse gives None, inverse is the unchecked version
 impl<S> Not for Mat2<S> where S: FloatType<S> {
     type Output = Mat2<S>;
 
     fn not(self) -> Self::Output {
-        self.inverse()
+        self.try_inverse().expect("Tried to invert a singular Mat2")
     }
 }
 
@@ -179,11 +195,12 @@ impl<S> Div<S> for Mat2<S> where S: FloatType<S> {
     }
 }
 
+//Multiplies by the inverse of rhs, which works the same way as the Not operator on singular matrices
 impl<S> Div<Mat2<S>> for Mat2<S> where S: FloatType<S> {
     type Output = Mat2<S>;
 
     fn div(self, rhs: Mat2<S>) -> Self::Output {
-        let inv_mat = rhs.inverse();
+        let inv_mat = !rhs;
         self * inv_mat
     }
 }
@@ -230,6 +247,23 @@ impl<S> fmt::Display for Mat2<S> where S: FloatType<S> {
     }
 }
 
+//Same layout as get_rotation_base, a unit complex number at angle theta gives the same matrix
+impl<S> From<Complex<S>> for Mat2<S> where S: FloatType<S> {
+    fn from(c: Complex<S>) -> Mat2<S> {
+        Mat2 {
+            r0: Vec2 { x: c.re, y: c.im },
+            r1: Vec2 { x: -c.im, y: c.re },
+        }
+    }
+}
+
+//Same as going through Complex
+impl<S> From<ga::Rotor2<S>> for Mat2<S> where S: FloatType<S> {
+    fn from(r: ga::Rotor2<S>) -> Mat2<S> {
+        Mat2::from(Complex::from(r))
+    }
+}
+
 impl<S> Default for Mat2<S> where S: FloatType<S> {
     fn default() -> Mat2<S> {
         Mat2::identity()
@@ -237,38 +271,20 @@ impl<S> Default for Mat2<S> where S: FloatType<S> {
 }
 
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Mat2<f32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Mat2(std::mem::transmute::<Mat2<f32>, [[f32; 2]; 2]>(self.transpose()))
+            glium::uniforms::UniformValue::Mat2(core::mem::transmute::<Mat2<f32>, [[f32; 2]; 2]>(self.transpose()))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Mat2<f64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::DoubleMat2(std::mem::transmute::<Mat2<f64>, [[f64; 2]; 2]>(self.transpose()))
+            glium::uniforms::UniformValue::DoubleMat2(core::mem::transmute::<Mat2<f64>, [[f64; 2]; 2]>(self.transpose()))
         }
     }
 }
-
-unsafe impl glium::vertex::Attribute for Mat2<f32> {
-    fn get_type() -> glium::vertex::AttributeType {
-        glium::vertex::AttributeType::F32x2x2
-    }
-
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
-        true
-    }
-}
-
-unsafe impl glium::vertex::Attribute for Mat2<f64> {
-    fn get_type() -> glium::vertex::AttributeType {
-        glium::vertex::AttributeType::F64x2x2
-    }
-
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
-        true
-    }
-}
\ No newline at end of file
@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -77,10 +77,40 @@ impl<S> Mat3<S> where S: FloatType<S> {
         }
     }
 
+    //Unchecked, singular matrices give non-finite elements, use try_inverse when that can happen
     pub fn inverse(&self) -> Mat3<S> {
         self.adjoint() / self.determinant()
     }
 
+    //Fast path for rotation matrices (orthonormal rows), whose inverse is their transpose, other matrices give wrong results
+    pub fn inverse_orthonormal(&self) -> Mat3<S> {
+        self.transpose()
+    }
+
+    pub fn try_inverse(&self) -> Option<Mat3<S>> {
+        self.try_inverse_with_epsilon(S::DEF_EPSILON)
+    }
+
+    //None for singular and ill-conditioned matrices, the determinant is compared to the product of the lengths of the rows
+    //(which bounds it), so the check does not depend on the scale of the matrix
+    pub fn try_inverse_with_epsilon(&self, epsilon: S) -> Option<Mat3<S>> {
+        let det = self.determinant();
+        if !det.is_finite() || det.abs() <= epsilon * self.r0.length() * self.r1.length() * self.r2.length() {
+            return None;
+        }
+        Some(self.adjoint() / det)
+    }
+
+    //Splits the matrix up into rotation, scale and shear (the translation is left at zero), see Decomposition,
+    //None for singular matrices
+    pub fn decompose(&self) -> Option<Decomposition<S>> {
+        Decomposition::from_axes(Vec3::zero(), *self)
+    }
+
+    pub fn recompose(rotation: Quat<S>, scale: Vec3<S>, shear: Vec3<S>) -> Mat3<S> {
+        Mat3::from(Decomposition::new(Vec3::zero(), rotation, scale, shear))
+    }
+
     pub fn transpose(&self) -> Mat3<S> {
         Mat3 {
             r0: Vec3 { x: self[0][0], y: self[1][0], z: self[2][0] },
@@ -93,40 +123,20 @@ impl<S> Mat3<S> where S: FloatType<S> {
         Mat3::get_rotation_mat_flex_euler_rad(angles * S::to_radians(S::one()), order)
     }
 
+    //The product of the single axis rotation matrices in the order of the name, so PHB is p * h * b,
+    //which is the same rotation as extrinsic PHB, see RotationOrder for how the angles are assigned for the proper Euler orders
     pub fn get_rotation_mat_flex_euler_rad(angles: Vec3<S>, order: RotationOrder) -> Mat3<S> {
-        let sin_pitch = angles.x.sin();
-        let cos_pitch = angles.x.cos();
-        let sin_heading = angles.y.sin();
-        let cos_heading = angles.y.cos();
-        let sin_bank = angles.z.sin();
-        let cos_bank = angles.z.cos();
-
-        let p = Mat3 {
-            r0: Vec3 { x: S::one(), y: S::zero(), z: S::zero() },
-            r1: Vec3 { x: S::zero(), y: cos_pitch, z: sin_pitch },
-            r2: Vec3 { x: S::zero(), y: -sin_pitch, z: cos_pitch },
-        };
-
-        let h = Mat3 {
-            r0: Vec3 { x: cos_heading, y: S::zero(), z: -sin_heading },
-            r1: Vec3 { x: S::zero(), y: S::one(), z: S::zero() },
-            r2: Vec3 { x: sin_heading, y: S::zero(), z: cos_heading },
-        };
+        let angles = order.get_ordered_angles(angles.x, angles.y, angles.z);
+        Mat3::get_rotation_mat_from_euler_rad(angles, order, EulerFrame::Extrinsic)
+    }
 
-        let b = Mat3 {
-            r0: Vec3 { x: cos_bank, y: sin_bank, z: S::zero() },
-            r1: Vec3 { x: -sin_bank, y: cos_bank, z: S::zero() },
-            r2: Vec3 { x: S::zero(), y: S::zero(), z: S::one() },
-        };
+    pub fn get_rotation_mat_from_euler_deg(angles: Vec3<S>, order: RotationOrder, frame: EulerFrame) -> Mat3<S> {
+        Mat3::get_rotation_mat_from_euler_rad(angles * S::one().to_radians(), order, frame)
+    }
 
-        match order {
-            RotationOrder::PHB => p * h * b,
-            RotationOrder::PBH => p * b * h,
-            RotationOrder::HPB => h * p * b,
-            RotationOrder::HBP => b * b * p,
-            RotationOrder::BPH => b * p * h,
-            RotationOrder::BHP => b * h * p,
-        }
+    //Same as Mat3::from(Quat::get_quat_from_euler_rad(angles, order, frame))
+    pub fn get_rotation_mat_from_euler_rad(angles: Vec3<S>, order: RotationOrder, frame: EulerFrame) -> Mat3<S> {
+        Mat3::from(Quat::get_quat_from_euler_rad(angles, order, frame))
     }
 
     pub fn get_rotation_mat_euler_upr_obj_deg(pitch: S, heading: S, bank: S) -> Mat3<S> {
@@ -174,17 +184,17 @@ impl<S> Mat3<S> where S: FloatType<S> {
     }
 
     pub fn get_euler_angles_obj_upr_rad(&self) -> Vec3<S> {
-        let sp = -self[2][1];
+        let sp = -self[1][2];
         let pitch = if sp <= -S::one() {
-            -S::from(std::f64::consts::FRAC_PI_2).unwrap()
+            -S::from(core::f64::consts::FRAC_PI_2).unwrap()
         } else if sp >= S::one() {
-            S::from(std::f64::consts::FRAC_PI_2).unwrap()
+            S::from(core::f64::consts::FRAC_PI_2).unwrap()
         } else {
             sp.asin()
         };
 
         if sp.abs() > S::from(0.9999).unwrap() {
-            let heading = -self[0][2].atan2(self[0][0]);
+            let heading = (-self[2][0]).atan2(self[0][0]);
             let bank = S::zero();
             Vec3 {
                 x: pitch,
@@ -193,7 +203,7 @@ impl<S> Mat3<S> where S: FloatType<S> {
             }
         } else {
             let heading = self[0][2].atan2(self[2][2]);
-            let bank = self[0][1].atan2(self[1][1]);
+            let bank = self[1][0].atan2(self[1][1]);
             Vec3 {
                 x: pitch,
                 y: heading,
@@ -202,7 +212,23 @@ impl<S> Mat3<S> where S: FloatType<S> {
         }
     }
 
-    //TODO: get euler angles upr obj
+    pub fn get_euler_angles_upr_obj_deg(&self) -> Vec3<S> {
+        self.get_euler_angles_upr_obj_rad() * S::one().to_degrees()
+    }
+
+    //The upright to object matrix is the transpose of the object to upright one
+    pub fn get_euler_angles_upr_obj_rad(&self) -> Vec3<S> {
+        self.transpose().get_euler_angles_obj_upr_rad()
+    }
+
+    pub fn get_euler_angles_deg(&self, order: RotationOrder, frame: EulerFrame) -> Vec3<S> {
+        self.get_euler_angles_rad(order, frame) * S::one().to_degrees()
+    }
+
+    //The inverse of get_rotation_mat_from_euler_rad, see Quat::get_euler_angles_rad for the ranges and gimbal lock
+    pub fn get_euler_angles_rad(&self, order: RotationOrder, frame: EulerFrame) -> Vec3<S> {
+        Quat::from(*self).get_euler_angles_rad(order, frame)
+    }
 
     pub fn get_angle_axis_mat_deg(n: Vec3<S>, theta: S) -> Mat3<S> {
         Mat3::get_angle_axis_mat_rad(n, theta.to_radians())
@@ -281,6 +307,46 @@ impl<S> Mat3<S> where S: FloatType<S> {
     pub fn scale_along_axis(&mut self, n: Vec3<S>, s: S) {
         *self *= Mat3::get_scale_along_axis_mat(n, s);
     }
+
+    //Bulk transforms, these take a slice and write to an out slice of the same length (they panic otherwise) or transform in place,
+    //a Mat3 has no translation, so points and vectors are transformed the same way
+    pub fn transform_points(&self, points: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        self.transform_vectors(points, out);
+    }
+
+    pub fn transform_points_in_place(&self, points: &mut [Vec3<S>]) {
+        self.transform_vectors_in_place(points);
+    }
+
+    pub fn transform_vectors(&self, vectors: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        assert_eq!(vectors.len(), out.len());
+        for (v, o) in vectors.iter().zip(out.iter_mut()) {
+            *o = *self * *v;
+        }
+    }
+
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vec3<S>]) {
+        for v in vectors.iter_mut() {
+            *v = *self * *v;
+        }
+    }
+
+    //Uses the inverse transpose, which is only calculated once, so normals stay perpendicular to their surfaces
+    //under non-uniform scale, the results are normalized again
+    pub fn transform_normals(&self, normals: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        assert_eq!(normals.len(), out.len());
+        let normal_mat = self.inverse().transpose();
+        for (n, o) in normals.iter().zip(out.iter_mut()) {
+            *o = (normal_mat * *n).normalized();
+        }
+    }
+
+    pub fn transform_normals_in_place(&self, normals: &mut [Vec3<S>]) {
+        let normal_mat = self.inverse().transpose();
+        for n in normals.iter_mut() {
+            *n = (normal_mat * *n).normalized();
+        }
+    }
 }
 
 impl<S> Index<usize> for Mat3<S> where S: FloatType<S> {
@@ -307,11 +373,12 @@ impl<S> IndexMut<usize> for Mat3<S> where S: FloatType<S> {
     }
 }
 
+//Checked in every build, panics where try_inverse gives None, inverse is the unchecked version
 impl<S> Not for Mat3<S> where S: FloatType<S> {
     type Output = Mat3<S>;
 
     fn not(self) -> Self::Output {
-        self.inverse()
+        self.try_inverse().expect("Tried to invert a singular Mat3")
     }
 }
 
@@ -391,11 +458,12 @@ impl<S> Div<S> for Mat3<S> where S: FloatType<S> {
     }
 }
 
+//Multiplies by the inverse of rhs, which works the same way as the Not operator on singular matrices
 impl<S> Div<Mat3<S>> for Mat3<S> where S: FloatType<S> {
     type Output = Mat3<S>;
 
     fn div(self, rhs: Mat3<S>) -> Self::Output {
-        let inv_mat = rhs.inverse();
+        let inv_mat = !rhs;
         self * inv_mat
     }
 }
@@ -477,44 +545,62 @@ impl<S> From<Mat2<S>> for Mat3<S> where S: FloatType<S> {
     }
 }
 
-impl<S> Default for Mat3<S> where S: FloatType<S> {
-    fn default() -> Mat3<S> {
-        Mat3::identity()
+//2D affine transform, the bottom row becomes 0 0 1
+impl<S> From<Mat3x2<S>> for Mat3<S> where S: FloatType<S> {
+    fn from(m: Mat3x2<S>) -> Mat3<S> {
+        Mat3 {
+            r0: m.r0,
+            r1: m.r1,
+            r2: Vec3 { x: S::zero(), y: S::zero(), z: S::one() },
+        }
     }
 }
 
-impl glium::uniforms::AsUniformValue for Mat3<f32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
-        unsafe {
-            glium::uniforms::UniformValue::Mat3(std::mem::transmute::<Mat3<f32>, [[f32; 3]; 3]>(self.transpose()))
+//Takes the upper-left 3x3 part, which holds the rotation and scale of an affine Mat4
+impl<S> From<Mat4<S>> for Mat3<S> where S: FloatType<S> {
+    fn from(mat: Mat4<S>) -> Mat3<S> {
+        Mat3 {
+            r0: Vec3::from(mat.r0),
+            r1: Vec3::from(mat.r1),
+            r2: Vec3::from(mat.r2),
         }
     }
 }
 
-impl glium::uniforms::AsUniformValue for Mat3<f64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
-        unsafe {
-            glium::uniforms::UniformValue::DoubleMat3(std::mem::transmute::<Mat3<f64>, [[f64; 3]; 3]>(self.transpose()))
-        }
+//The translation can not be represented and is dropped
+impl<S> From<Decomposition<S>> for Mat3<S> where S: FloatType<S> {
+    fn from(d: Decomposition<S>) -> Mat3<S> {
+        d.get_rotation_mat() * d.get_shear_mat() * Mat3::get_uniform_scale_mat(d.scale)
     }
 }
 
-unsafe impl glium::vertex::Attribute for Mat3<f32> {
-    fn get_type() -> glium::vertex::AttributeType {
-        glium::vertex::AttributeType::F32x3x3
+//Same as going through Quat, so Mat3::from(Rotor3::from(q)) == Mat3::from(q)
+impl<S> From<ga::Rotor3<S>> for Mat3<S> where S: FloatType<S> {
+    fn from(r: ga::Rotor3<S>) -> Mat3<S> {
+        Mat3::from(Quat::from(r))
     }
+}
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
-        true
+impl<S> Default for Mat3<S> where S: FloatType<S> {
+    fn default() -> Mat3<S> {
+        Mat3::identity()
     }
 }
 
-unsafe impl glium::vertex::Attribute for Mat3<f64> {
-    fn get_type() -> glium::vertex::AttributeType {
-        glium::vertex::AttributeType::F64x3x3
+#[cfg(feature = "glium")]
+impl glium::uniforms::AsUniformValue for Mat3<f32> {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
+        unsafe {
+            glium::uniforms::UniformValue::Mat3(core::mem::transmute::<Mat3<f32>, [[f32; 3]; 3]>(self.transpose()))
+        }
     }
+}
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
-        true
+#[cfg(feature = "glium")]
+impl glium::uniforms::AsUniformValue for Mat3<f64> {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
+        unsafe {
+            glium::uniforms::UniformValue::DoubleMat3(core::mem::transmute::<Mat3<f64>, [[f64; 3]; 3]>(self.transpose()))
+        }
     }
-}
\ No newline at end of file
+}
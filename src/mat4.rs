@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -129,73 +129,87 @@ impl<S> Mat4<S> where S: FloatType<S> {
         Mat4 { r0, r1, r2, r3 }
     }
 
-    //This version does not use the adjoint and determinant functions, because they share a bunch of calculations
-    //that are best left un-abstracted for matrices of this size and up (for reduced memory usage, less redundant computation and potential compiler optimizations)
+    //Unchecked, singular matrices give non-finite elements, use try_inverse when that can happen
     pub fn inverse(&self) -> Mat4<S> {
-        //Pre-calculating sub-factors, since all of them are used 4 times
-        let sf00 = self[2][2] * self[3][3] - self[3][2] * self[2][3];
-        let sf01 = self[2][1] * self[3][3] - self[3][1] * self[2][3];
-        let sf02 = self[2][1] * self[3][2] - self[3][1] * self[2][2];
-        let sf03 = self[2][0] * self[3][3] - self[3][0] * self[2][3];
-        let sf04 = self[2][0] * self[3][2] - self[3][0] * self[2][2];
-        let sf05 = self[2][0] * self[3][1] - self[3][0] * self[2][1];
-        let sf06 = self[1][2] * self[3][3] - self[3][2] * self[1][3];
-        let sf07 = self[1][1] * self[3][3] - self[3][1] * self[1][3];
-        let sf08 = self[1][1] * self[3][2] - self[3][1] * self[1][2];
-        let sf09 = self[1][0] * self[3][3] - self[3][0] * self[1][3];
-        let sf10 = self[1][0] * self[3][2] - self[3][0] * self[1][2];
-        let sf11 = self[1][0] * self[3][1] - self[3][0] * self[1][1];
-        let sf12 = self[1][2] * self[2][3] - self[2][2] * self[1][3];
-        let sf13 = self[1][1] * self[2][3] - self[2][1] * self[1][3];
-        let sf14 = self[1][1] * self[2][2] - self[2][1] * self[1][2];
-        let sf15 = self[1][0] * self[2][3] - self[2][0] * self[1][3];
-        let sf16 = self[1][0] * self[2][2] - self[2][0] * self[1][2];
-        let sf17 = self[1][0] * self[2][1] - self[2][0] * self[1][1];
+        simd::mat4_inverse(self)
+    }
 
+    pub fn try_inverse(&self) -> Option<Mat4<S>> {
+        self.try_inverse_with_epsilon(S::DEF_EPSILON)
+    }
 
-        let r0 = Vec4 {
-            x: self[1][1] * sf00 - self[1][2] * sf01 + self[1][3] * sf02,
-            y: -(self[0][1] * sf00 - self[0][2] * sf01 + self[0][3] * sf02),
-            z: self[0][1] * sf06 - self[0][2] * sf07 + self[0][3] * sf08,
-            w: -(self[0][1] * sf12 - self[0][2] * sf13 + self[0][3] * sf14),
-        };
+    //None for singular and ill-conditioned matrices, the determinant is compared to the product of the lengths of the rows
+    //(which bounds it), so the check does not depend on the scale of the matrix
+    pub fn try_inverse_with_epsilon(&self, epsilon: S) -> Option<Mat4<S>> {
+        let adj = self.adjoint();
+        let det = self[0][0] * adj[0][0] + self[1][0] * adj[0][1] + self[2][0] * adj[0][2] + self[3][0] * adj[0][3];
+        if !det.is_finite() || det.abs() <= epsilon * self.r0.length() * self.r1.length() * self.r2.length() * self.r3.length() {
+            return None;
+        }
+        Some(adj / det)
+    }
 
-        let r1 = Vec4 {
-            x: -(self[1][0] * sf00 - self[1][2] * sf03 + self[1][3] * sf04),
-            y: self[0][0] * sf00 - self[0][2] * sf03 + self[0][3] * sf04,
-            z: -(self[0][0] * sf06 - self[0][2] * sf09 + self[0][3] * sf10),
-            w: self[0][0] * sf12 - self[0][2] * sf15 + self[0][3] * sf16,
-        };
+    //Whether the bottom row is 0 0 0 1, so the matrix does not project
+    pub fn is_affine(&self) -> bool {
+        self[3][0].approx_eq(S::zero(), S::DEF_EPSILON) &&
+            self[3][1].approx_eq(S::zero(), S::DEF_EPSILON) &&
+            self[3][2].approx_eq(S::zero(), S::DEF_EPSILON) &&
+            self[3][3].approx_eq(S::one(), S::DEF_EPSILON)
+    }
 
-        let r2 = Vec4 {
-            x: self[1][0] * sf01 - self[1][1] * sf03 + self[1][3] * sf05,
-            y: -(self[0][0] * sf01 - self[0][1] * sf03 + self[0][3] * sf05),
-            z: self[0][0] * sf07 - self[0][1] * sf09 + self[0][3] * sf11,
-            w: -(self[0][0] * sf13 - self[0][1] * sf15 + self[0][3] * sf17),
-        };
+    //Fast path for affine matrices, only the upper left 3x3 part gets inverted, unchecked just like inverse
+    pub fn inverse_affine(&self) -> Mat4<S> {
+        debug_assert!(self.is_affine());
+        Mat4::get_affine_mat(Mat3::from(*self).inverse(), self.get_translation())
+    }
 
-        let r3 = Vec4 {
-            x: -(self[1][0] * sf02 - self[1][1] * sf04 + self[1][2] * sf05),
-            y: self[0][0] * sf02 - self[0][1] * sf04 + self[0][2] * sf05,
-            z: -(self[0][0] * sf08 - self[0][1] * sf10 + self[0][2] * sf11),
-            w: self[0][0] * sf14 - self[0][1] * sf16 + self[0][2] * sf17,
-        };
+    //Fast path for rigid transforms (rotation and translation only), the rotation gets transposed and the translation negated,
+    //matrices with scaling or shear give wrong results
+    pub fn inverse_rigid(&self) -> Mat4<S> {
+        debug_assert!(self.is_affine());
+        Mat4::get_affine_mat(Mat3::from(*self).inverse_orthonormal(), self.get_translation())
+    }
 
-        let adj = Mat4 { r0, r1, r2, r3 };
+    //None for singular matrices and for matrices that are not affine
+    pub fn try_inverse_affine(&self) -> Option<Mat4<S>> {
+        if !self.is_affine() {
+            return None;
+        }
+        let inv = Mat3::from(*self).try_inverse()?;
+        Some(Mat4::get_affine_mat(inv, self.get_translation()))
+    }
 
-        let det = self[0][0] * adj[0][0] + self[1][0] * adj[0][1] + self[2][0] * adj[0][2] + self[3][0] * adj[0][3];
+    pub fn get_translation(&self) -> Vec3<S> {
+        Vec3 { x: self[0][3], y: self[1][3], z: self[2][3] }
+    }
+
+    //The inverse of the affine matrix with the translation t and the 3x3 part that has inv as its inverse
+    fn get_affine_mat(inv: Mat3<S>, t: Vec3<S>) -> Mat4<S> {
+        let inv_t = -(inv * t);
+        Mat4 {
+            r0: Vec4 { x: inv[0][0], y: inv[0][1], z: inv[0][2], w: inv_t.x },
+            r1: Vec4 { x: inv[1][0], y: inv[1][1], z: inv[1][2], w: inv_t.y },
+            r2: Vec4 { x: inv[2][0], y: inv[2][1], z: inv[2][2], w: inv_t.z },
+            r3: Vec4 { x: S::zero(), y: S::zero(), z: S::zero(), w: S::one() },
+        }
+    }
+
+    //Splits the matrix up into translation, rotation, scale and shear, see Decomposition,
+    //None for singular matrices and for projections (anything with a bottom row other than 0 0 0 1)
+    pub fn decompose(&self) -> Option<Decomposition<S>> {
+        if !self.is_affine() {
+            return None;
+        }
+        Decomposition::from_axes(self.get_translation(), Mat3::from(*self))
+    }
 
-        adj / det
+    pub fn recompose(translation: Vec3<S>, rotation: Quat<S>, scale: Vec3<S>, shear: Vec3<S>) -> Mat4<S> {
+        Mat4::from(Decomposition::new(translation, rotation, scale, shear))
     }
 
     //Transposes the matrix (swaps the elements over the diagonal)
     pub fn transpose(&self) -> Mat4<S> {
-        Mat4 {
-            r0: Vec4 { x: self[0][0], y: self[1][0], z: self[2][0], w: self[3][0] },
-            r1: Vec4 { x: self[0][1], y: self[1][1], z: self[2][1], w: self[3][1] },
-            r2: Vec4 { x: self[0][2], y: self[1][2], z: self[2][2], w: self[3][2] },
-            r3: Vec4 { x: self[0][3], y: self[1][3], z: self[2][3], w: self[3][3] },
-        }
+        simd::mat4_transpose(self)
     }
 
     //From base matrices
@@ -208,6 +222,14 @@ impl<S> Mat4<S> where S: FloatType<S> {
         Mat4::from(Mat3::get_rotation_mat_flex_euler_rad(angles, order))
     }
 
+    pub fn get_rotation_mat_from_euler_deg(angles: Vec3<S>, order: RotationOrder, frame: EulerFrame) -> Mat4<S> {
+        Mat4::from(Mat3::get_rotation_mat_from_euler_deg(angles, order, frame))
+    }
+
+    pub fn get_rotation_mat_from_euler_rad(angles: Vec3<S>, order: RotationOrder, frame: EulerFrame) -> Mat4<S> {
+        Mat4::from(Mat3::get_rotation_mat_from_euler_rad(angles, order, frame))
+    }
+
     pub fn get_rotation_mat_euler_upr_obj_deg(pitch: S, heading: S, bank: S) -> Mat4<S> {
         Mat4::from(Mat3::get_rotation_mat_euler_upr_obj_deg(pitch, heading, bank))
     }
@@ -250,6 +272,122 @@ impl<S> Mat4<S> where S: FloatType<S> {
         trans
     }
 
+    //Projection matrices, these are laid out for column vectors (proj * view * model * pos), just like the rest of Mat4
+
+    pub fn get_perspective_mat_deg(fov_y: S, aspect: S, near: S, far: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        Mat4::get_perspective_mat_rad(fov_y.to_radians(), aspect, near, far, hand, depth)
+    }
+
+    //Symmetric perspective projection, the aspect ratio is width / height
+    pub fn get_perspective_mat_rad(fov_y: S, aspect: S, near: S, far: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        let top = near * (fov_y / (S::one() + S::one())).tan();
+        let right = top * aspect;
+        Mat4::get_frustum_mat(-right, right, -top, top, near, far, hand, depth)
+    }
+
+    //Perspective projection from the bounds of the frustum on the near plane
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_frustum_mat(left: S, right: S, bottom: S, top: S, near: S, far: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        let two = S::one() + S::one();
+        let h = hand.sign::<S>();
+        let (z_scale, z_offset) = match depth {
+            DepthRange::NegOneToOne => ((far + near) / (far - near), -(two * far * near) / (far - near)),
+            DepthRange::ZeroToOne => (far / (far - near), -(far * near) / (far - near)),
+        };
+
+        Mat4 {
+            r0: Vec4 { x: two * near / (right - left), y: S::zero(), z: -h * (right + left) / (right - left), w: S::zero() },
+            r1: Vec4 { x: S::zero(), y: two * near / (top - bottom), z: -h * (top + bottom) / (top - bottom), w: S::zero() },
+            r2: Vec4 { x: S::zero(), y: S::zero(), z: h * z_scale, w: z_offset },
+            r3: Vec4 { x: S::zero(), y: S::zero(), z: h, w: S::zero() },
+        }
+    }
+
+    pub fn get_infinite_perspective_mat_deg(fov_y: S, aspect: S, near: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        Mat4::get_infinite_perspective_mat_rad(fov_y.to_radians(), aspect, near, hand, depth)
+    }
+
+    //Perspective projection with the far plane at infinity (the limit of get_perspective_mat_rad as far grows)
+    pub fn get_infinite_perspective_mat_rad(fov_y: S, aspect: S, near: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        let two = S::one() + S::one();
+        let h = hand.sign::<S>();
+        let f = S::one() / (fov_y / two).tan();
+        let z_offset = match depth {
+            DepthRange::NegOneToOne => -two * near,
+            DepthRange::ZeroToOne => -near,
+        };
+
+        Mat4 {
+            r0: Vec4 { x: f / aspect, y: S::zero(), z: S::zero(), w: S::zero() },
+            r1: Vec4 { x: S::zero(), y: f, z: S::zero(), w: S::zero() },
+            r2: Vec4 { x: S::zero(), y: S::zero(), z: h, w: z_offset },
+            r3: Vec4 { x: S::zero(), y: S::zero(), z: h, w: S::zero() },
+        }
+    }
+
+    pub fn get_reversed_z_perspective_mat_deg(fov_y: S, aspect: S, near: S, far: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        Mat4::get_reversed_z_perspective_mat_rad(fov_y.to_radians(), aspect, near, far, hand, depth)
+    }
+
+    //Perspective projection that maps the near plane to 1 and the far plane to the lowest depth value
+    pub fn get_reversed_z_perspective_mat_rad(fov_y: S, aspect: S, near: S, far: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        Mat4::get_perspective_mat_rad(fov_y, aspect, near, far, hand, depth).reverse_depth(depth)
+    }
+
+    pub fn get_infinite_reversed_z_perspective_mat_deg(fov_y: S, aspect: S, near: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        Mat4::get_infinite_reversed_z_perspective_mat_rad(fov_y.to_radians(), aspect, near, hand, depth)
+    }
+
+    pub fn get_infinite_reversed_z_perspective_mat_rad(fov_y: S, aspect: S, near: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        Mat4::get_infinite_perspective_mat_rad(fov_y, aspect, near, hand, depth).reverse_depth(depth)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_orthographic_mat(left: S, right: S, bottom: S, top: S, near: S, far: S, hand: Handedness, depth: DepthRange) -> Mat4<S> {
+        let two = S::one() + S::one();
+        let h = hand.sign::<S>();
+        let (z_scale, z_offset) = match depth {
+            DepthRange::NegOneToOne => (two / (far - near), -(far + near) / (far - near)),
+            DepthRange::ZeroToOne => (S::one() / (far - near), -near / (far - near)),
+        };
+
+        Mat4 {
+            r0: Vec4 { x: two / (right - left), y: S::zero(), z: S::zero(), w: -(right + left) / (right - left) },
+            r1: Vec4 { x: S::zero(), y: two / (top - bottom), z: S::zero(), w: -(top + bottom) / (top - bottom) },
+            r2: Vec4 { x: S::zero(), y: S::zero(), z: h * z_scale, w: z_offset },
+            r3: Vec4 { x: S::zero(), y: S::zero(), z: S::zero(), w: S::one() },
+        }
+    }
+
+    //View matrices, the up vector only has to be roughly up, it gets re-orthogonalized against the view direction
+
+    pub fn look_at(eye: Vec3<S>, target: Vec3<S>, up: Vec3<S>, hand: Handedness) -> Mat4<S> {
+        Mat4::look_to(eye, target - eye, up, hand)
+    }
+
+    pub fn look_to(eye: Vec3<S>, dir: Vec3<S>, up: Vec3<S>, hand: Handedness) -> Mat4<S> {
+        //The z axis of view space points along the view direction for left-handed views and against it for right-handed ones
+        let z_axis = dir.normalized() * hand.sign::<S>();
+        let x_axis = up.cross(z_axis).normalized();
+        let y_axis = z_axis.cross(x_axis);
+
+        Mat4 {
+            r0: Vec4 { x: x_axis.x, y: x_axis.y, z: x_axis.z, w: -x_axis.dot(eye) },
+            r1: Vec4 { x: y_axis.x, y: y_axis.y, z: y_axis.z, w: -y_axis.dot(eye) },
+            r2: Vec4 { x: z_axis.x, y: z_axis.y, z: z_axis.z, w: -z_axis.dot(eye) },
+            r3: Vec4 { x: S::zero(), y: S::zero(), z: S::zero(), w: S::one() },
+        }
+    }
+
+    //Flips the depth output of a projection so that near ends up at 1, [-1, 1] negates it, [0, 1] does 1 - z
+    fn reverse_depth(self, depth: DepthRange) -> Mat4<S> {
+        let r2 = match depth {
+            DepthRange::NegOneToOne => -self.r2,
+            DepthRange::ZeroToOne => self.r3 - self.r2,
+        };
+        Mat4 { r2, ..self }
+    }
+
     //Direct operations on Mat4
 
     pub fn rotate_by_euler_flex_deg(&mut self, angles: Vec3<S>, order: RotationOrder) {
@@ -295,6 +433,67 @@ impl<S> Mat4<S> where S: FloatType<S> {
     pub fn translate(&mut self, trans: Vec3<S>) {
         *self *= Mat4::get_translation_mat(trans);
     }
+
+    //Bulk transforms, these take a slice and write to an out slice of the same length (they panic otherwise) or transform in place,
+    //anything that only depends on the matrix (like the inverse transpose for normals) is worked out once per call
+
+    //Mat4 * (p, 1) for every point, the bottom row is ignored, use transform_points_perspective for projections
+    pub fn transform_points(&self, points: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        assert_eq!(points.len(), out.len());
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            *o = self.transform_point(*p);
+        }
+    }
+
+    pub fn transform_points_in_place(&self, points: &mut [Vec3<S>]) {
+        for p in points.iter_mut() {
+            *p = self.transform_point(*p);
+        }
+    }
+
+    //Mat4 * (p, 1) for every point, divided by the resulting w
+    pub fn transform_points_perspective(&self, points: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        assert_eq!(points.len(), out.len());
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            *o = self.transform_point_perspective(*p);
+        }
+    }
+
+    pub fn transform_points_perspective_in_place(&self, points: &mut [Vec3<S>]) {
+        for p in points.iter_mut() {
+            *p = self.transform_point_perspective(*p);
+        }
+    }
+
+    //Mat4 * (v, 0) for every direction, so the translation does not affect them
+    pub fn transform_vectors(&self, vectors: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        Mat3::from(*self).transform_vectors(vectors, out);
+    }
+
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vec3<S>]) {
+        Mat3::from(*self).transform_vectors_in_place(vectors);
+    }
+
+    //Uses the inverse transpose of the upper left 3x3 part, so normals stay perpendicular to their surfaces under non-uniform scale,
+    //the results are normalized again
+    pub fn transform_normals(&self, normals: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        Mat3::from(*self).transform_normals(normals, out);
+    }
+
+    pub fn transform_normals_in_place(&self, normals: &mut [Vec3<S>]) {
+        Mat3::from(*self).transform_normals_in_place(normals);
+    }
+
+    fn transform_point(&self, p: Vec3<S>) -> Vec3<S> {
+        let h = *self * Vec4 { x: p.x, y: p.y, z: p.z, w: S::one() };
+        Vec3 { x: h.x, y: h.y, z: h.z }
+    }
+
+    fn transform_point_perspective(&self, p: Vec3<S>) -> Vec3<S> {
+        let h = *self * Vec4 { x: p.x, y: p.y, z: p.z, w: S::one() };
+        let inv_w = S::one() / h.w;
+        Vec3 { x: h.x * inv_w, y: h.y * inv_w, z: h.z * inv_w }
+    }
 }
 
 impl<S> Index<usize> for Mat4<S> where S: FloatType<S> {
@@ -324,11 +523,12 @@ impl<S> IndexMut<usize> for Mat4<S> where S: FloatType<S> {
 }
 
 
+//Checked in every build, panics where try_inverse gives None, inverse is the unchecked version
 impl<S> Not for Mat4<S> where S: FloatType<S> {
     type Output = Mat4<S>;
 
     fn not(self) -> Self::Output {
-        self.inverse()
+        self.try_inverse().expect("Tried to invert a singular Mat4")
     }
 }
 
@@ -350,13 +550,7 @@ impl<S> Mul<Mat4<S>> for Mat4<S> where S: FloatType<S> {
     type Output = Mat4<S>;
 
     fn mul(self, rhs: Mat4<S>) -> Self::Output {
-        let rhs = rhs.transpose();
-        Mat4 {
-            r0: Vec4 { x: self[0].dot(rhs[0]), y: self[0].dot(rhs[1]), z: self[0].dot(rhs[2]), w: self[0].dot(rhs[3]) },
-            r1: Vec4 { x: self[1].dot(rhs[0]), y: self[1].dot(rhs[1]), z: self[1].dot(rhs[2]), w: self[1].dot(rhs[3]) },
-            r2: Vec4 { x: self[2].dot(rhs[0]), y: self[2].dot(rhs[1]), z: self[2].dot(rhs[2]), w: self[2].dot(rhs[3]) },
-            r3: Vec4 { x: self[3].dot(rhs[0]), y: self[3].dot(rhs[1]), z: self[3].dot(rhs[2]), w: self[3].dot(rhs[3]) },
-        }
+        simd::mat4_mul(&self, &rhs)
     }
 }
 
@@ -364,12 +558,7 @@ impl<S> Mul<Vec4<S>> for Mat4<S> where S: FloatType<S> {
     type Output = Vec4<S>;
 
     fn mul(self, rhs: Vec4<S>) -> Self::Output {
-        Vec4 {
-            x: self.r0.dot(rhs),
-            y: self.r1.dot(rhs),
-            z: self.r2.dot(rhs),
-            w: self.r3.dot(rhs),
-        }
+        simd::mat4_mul_vec4(&self, rhs)
     }
 }
 
@@ -405,11 +594,12 @@ impl<S> Div<S> for Mat4<S> where S: FloatType<S> {
     }
 }
 
+//Multiplies by the inverse of rhs, which works the same way as the Not operator on singular matrices
 impl<S> Div<Mat4<S>> for Mat4<S> where S: FloatType<S> {
     type Output = Mat4<S>;
 
     fn div(self, rhs: Mat4<S>) -> Self::Output {
-        let inv_mat = rhs.inverse();
+        let inv_mat = !rhs;
         self * inv_mat
     }
 }
@@ -491,51 +681,79 @@ impl<S> From<Mat3<S>> for Mat4<S> where S: FloatType<S> {
     }
 }
 
+//Affine transform, the bottom row becomes 0 0 0 1
+impl<S> From<Mat4x3<S>> for Mat4<S> where S: FloatType<S> {
+    fn from(m: Mat4x3<S>) -> Mat4<S> {
+        Mat4 {
+            r0: m.r0,
+            r1: m.r1,
+            r2: m.r2,
+            r3: Vec4 { x: S::zero(), y: S::zero(), z: S::zero(), w: S::one() },
+        }
+    }
+}
+
 impl<S> From<Quat<S>> for Mat4<S> where S: FloatType<S> {
     fn from(quat: Quat<S>) -> Mat4<S> {
         Mat4::from(Mat3::from(quat))
     }
 }
 
+//The rotation block is the transpose of Mat3::from(rotation), as the matrix gets applied to column vectors
+impl<S> From<Transform<S>> for Mat4<S> where S: FloatType<S> {
+    fn from(t: Transform<S>) -> Mat4<S> {
+        let rotation = Mat3::from(t.rotation.normalized()).transpose() * Mat3::get_uniform_scale_mat(t.scale);
+        let mut m = Mat4::from(rotation);
+        m[0][3] = t.translation.x;
+        m[1][3] = t.translation.y;
+        m[2][3] = t.translation.z;
+        m
+    }
+}
+
+//Normalizes first, so the rotation block stays orthonormal
+impl<S> From<DualQuat<S>> for Mat4<S> where S: FloatType<S> {
+    fn from(dq: DualQuat<S>) -> Mat4<S> {
+        let dq = dq.normalized();
+        let translation = dq.get_translation();
+        let mut m = Mat4::from(Mat3::from(dq.real).transpose());
+        m[0][3] = translation.x;
+        m[1][3] = translation.y;
+        m[2][3] = translation.z;
+        m
+    }
+}
+
+impl<S> From<Decomposition<S>> for Mat4<S> where S: FloatType<S> {
+    fn from(d: Decomposition<S>) -> Mat4<S> {
+        let mut m = Mat4::from(Mat3::from(d));
+        m[0][3] = d.translation.x;
+        m[1][3] = d.translation.y;
+        m[2][3] = d.translation.z;
+        m
+    }
+}
+
 impl<S> Default for Mat4<S> where S: FloatType<S> {
     fn default() -> Mat4<S> {
         Mat4::identity()
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Mat4<f32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Mat4(std::mem::transmute::<Mat4<f32>, [[f32; 4]; 4]>(self.transpose()))
+            glium::uniforms::UniformValue::Mat4(core::mem::transmute::<Mat4<f32>, [[f32; 4]; 4]>(self.transpose()))
         }
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Mat4<f64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::DoubleMat4(std::mem::transmute::<Mat4<f64>, [[f64; 4]; 4]>(self.transpose()))
+            glium::uniforms::UniformValue::DoubleMat4(core::mem::transmute::<Mat4<f64>, [[f64; 4]; 4]>(self.transpose()))
         }
     }
 }
-
-
-unsafe impl glium::vertex::Attribute for Mat4<f32> {
-    fn get_type() -> glium::vertex::AttributeType {
-        glium::vertex::AttributeType::F32x4x4
-    }
-
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
-        true
-    }
-}
-
-unsafe impl glium::vertex::Attribute for Mat4<f64> {
-    fn get_type() -> glium::vertex::AttributeType {
-        glium::vertex::AttributeType::F64x4x4
-    }
-
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
-        true
-    }
-}
\ No newline at end of file
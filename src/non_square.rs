@@ -0,0 +1,222 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//The GLSL non-square matrices, MatCxR has C columns and R rows, just like GLSL's matCxR, so it takes a VecC to a VecR,
+//they are stored row-major like the square matrices (R rows of VecC), Mat4x3 is an affine Mat4 without the bottom row,
+//and Mat3x2 is the same for 2D affine transforms
+
+macro_rules! non_square_mat {
+    ($mat:ident, $transposed:ident, $vec_c:ident, $vec_r:ident, $c:expr, $r:expr, [$($index:literal => $row:ident),+]) => {
+        #[repr(C)]
+        #[derive(Copy, Clone, Debug)]
+        pub struct $mat<S> {
+            $(pub $row: $vec_c<S>,)+
+        }
+
+        impl<S> $mat<S> where S: FloatType<S> {
+            //Ones on the diagonal, like the GLSL constructor that takes a single 1.0
+            pub fn identity() -> $mat<S> {
+                let mut m = $mat::empty();
+                for i in 0..(if $c < $r { $c } else { $r }) {
+                    m[i][i] = S::one();
+                }
+                m
+            }
+
+            pub fn empty() -> $mat<S> {
+                $mat { $($row: $vec_c::zero(),)+ }
+            }
+
+            pub fn new_from_rows($($row: $vec_c<S>),+) -> $mat<S> {
+                $mat { $($row,)+ }
+            }
+
+            pub fn transpose(&self) -> $transposed<S> {
+                let mut t = $transposed::empty();
+                for row in 0..$r {
+                    for col in 0..$c {
+                        t[col][row] = self[row][col];
+                    }
+                }
+                t
+            }
+        }
+
+        impl<S> Index<usize> for $mat<S> where S: FloatType<S> {
+            type Output = $vec_c<S>;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    $($index => &self.$row,)+
+                    _ => panic!("Requested an invalid row of a {}: {}", stringify!($mat), index)
+                }
+            }
+        }
+
+        impl<S> IndexMut<usize> for $mat<S> where S: FloatType<S> {
+            fn index_mut(&mut self, index: usize) -> &mut $vec_c<S> {
+                match index {
+                    $($index => &mut self.$row,)+
+                    _ => panic!("Requested an invalid row of a {}: {}", stringify!($mat), index)
+                }
+            }
+        }
+
+        impl<S> Neg for $mat<S> where S: FloatType<S> {
+            type Output = $mat<S>;
+
+            fn neg(self) -> Self::Output {
+                $mat { $($row: -self.$row,)+ }
+            }
+        }
+
+        impl<S> Mul<$vec_c<S>> for $mat<S> where S: FloatType<S> {
+            type Output = $vec_r<S>;
+
+            fn mul(self, rhs: $vec_c<S>) -> Self::Output {
+                let mut v = $vec_r::zero();
+                for row in 0..$r {
+                    v[row] = self[row].dot(rhs);
+                }
+                v
+            }
+        }
+
+        impl<S> Mul<S> for $mat<S> where S: FloatType<S> {
+            type Output = $mat<S>;
+
+            fn mul(self, rhs: S) -> Self::Output {
+                $mat { $($row: self.$row * rhs,)+ }
+            }
+        }
+
+        impl<S> MulAssign<S> for $mat<S> where S: FloatType<S> {
+            fn mul_assign(&mut self, rhs: S) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<S> Div<S> for $mat<S> where S: FloatType<S> {
+            type Output = $mat<S>;
+
+            fn div(self, rhs: S) -> Self::Output {
+                let inv_scale = S::one() / rhs;
+                self * inv_scale
+            }
+        }
+
+        impl<S> DivAssign<S> for $mat<S> where S: FloatType<S> {
+            fn div_assign(&mut self, rhs: S) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<S> From<[[S; $c]; $r]> for $mat<S> where S: FloatType<S> {
+            fn from(arr: [[S; $c]; $r]) -> $mat<S> {
+                $mat { $($row: $vec_c::from(arr[$index]),)+ }
+            }
+        }
+
+        impl<S> PartialEq for $mat<S> where S: FloatType<S> {
+            fn eq(&self, other: &$mat<S>) -> bool {
+                $(self.$row == other.$row)&&+
+            }
+        }
+
+        impl<S> fmt::Display for $mat<S> where S: FloatType<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                for row in 0..$r {
+                    let (open, close) = if row == 0 { ("⌈", "⌉\n") } else if row == $r - 1 { ("⌊", "⌋") } else { ("|", "|\n") };
+                    write!(f, "{}", open)?;
+                    for col in 0..$c {
+                        write!(f, "{}{:.2}", if col == 0 { "" } else { " " }, self[row][col])?;
+                    }
+                    write!(f, "{}", close)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<S> Default for $mat<S> where S: FloatType<S> {
+            fn default() -> $mat<S> {
+                $mat::identity()
+            }
+        }
+    };
+}
+
+//Matrix products, lhs * rhs is only defined when the columns of lhs match the rows of rhs
+macro_rules! mat_mul {
+    ($lhs:ident, $rhs:ident, $out:ident, $rows:expr, $inner:expr, $cols:expr) => {
+        impl<S> Mul<$rhs<S>> for $lhs<S> where S: FloatType<S> {
+            type Output = $out<S>;
+
+            fn mul(self, rhs: $rhs<S>) -> Self::Output {
+                let mut out = $out::empty();
+                for row in 0..$rows {
+                    for col in 0..$cols {
+                        let mut sum = S::zero();
+                        for k in 0..$inner {
+                            sum = sum + self[row][k] * rhs[k][col];
+                        }
+                        out[row][col] = sum;
+                    }
+                }
+                out
+            }
+        }
+    };
+}
+
+non_square_mat!(Mat2x3, Mat3x2, Vec2, Vec3, 2, 3, [0 => r0, 1 => r1, 2 => r2]);
+non_square_mat!(Mat2x4, Mat4x2, Vec2, Vec4, 2, 4, [0 => r0, 1 => r1, 2 => r2, 3 => r3]);
+non_square_mat!(Mat3x2, Mat2x3, Vec3, Vec2, 3, 2, [0 => r0, 1 => r1]);
+non_square_mat!(Mat3x4, Mat4x3, Vec3, Vec4, 3, 4, [0 => r0, 1 => r1, 2 => r2, 3 => r3]);
+non_square_mat!(Mat4x2, Mat2x4, Vec4, Vec2, 4, 2, [0 => r0, 1 => r1]);
+non_square_mat!(Mat4x3, Mat3x4, Vec4, Vec3, 4, 3, [0 => r0, 1 => r1, 2 => r2]);
+
+mat_mul!(Mat2, Mat3x2, Mat3x2, 2, 2, 3);
+mat_mul!(Mat2, Mat4x2, Mat4x2, 2, 2, 4);
+mat_mul!(Mat2x3, Mat2, Mat2x3, 3, 2, 2);
+mat_mul!(Mat2x3, Mat3x2, Mat3, 3, 2, 3);
+mat_mul!(Mat2x3, Mat4x2, Mat4x3, 3, 2, 4);
+mat_mul!(Mat2x4, Mat2, Mat2x4, 4, 2, 2);
+mat_mul!(Mat2x4, Mat3x2, Mat3x4, 4, 2, 3);
+mat_mul!(Mat2x4, Mat4x2, Mat4, 4, 2, 4);
+mat_mul!(Mat3x2, Mat2x3, Mat2, 2, 3, 2);
+mat_mul!(Mat3x2, Mat3, Mat3x2, 2, 3, 3);
+mat_mul!(Mat3x2, Mat4x3, Mat4x2, 2, 3, 4);
+mat_mul!(Mat3, Mat2x3, Mat2x3, 3, 3, 2);
+mat_mul!(Mat3, Mat4x3, Mat4x3, 3, 3, 4);
+mat_mul!(Mat3x4, Mat2x3, Mat2x4, 4, 3, 2);
+mat_mul!(Mat3x4, Mat3, Mat3x4, 4, 3, 3);
+mat_mul!(Mat3x4, Mat4x3, Mat4, 4, 3, 4);
+mat_mul!(Mat4x2, Mat2x4, Mat2, 2, 4, 2);
+mat_mul!(Mat4x2, Mat3x4, Mat3x2, 2, 4, 3);
+mat_mul!(Mat4x2, Mat4, Mat4x2, 2, 4, 4);
+mat_mul!(Mat4x3, Mat2x4, Mat2x3, 3, 4, 2);
+mat_mul!(Mat4x3, Mat3x4, Mat3, 3, 4, 3);
+mat_mul!(Mat4x3, Mat4, Mat4x3, 3, 4, 4);
+mat_mul!(Mat4, Mat2x4, Mat2x4, 4, 4, 2);
+mat_mul!(Mat4, Mat3x4, Mat3x4, 4, 4, 3);
+
+impl<S> From<Mat3<S>> for Mat3x2<S> where S: FloatType<S> {
+    fn from(m: Mat3<S>) -> Mat3x2<S> {
+        Mat3x2 {
+            r0: m.r0,
+            r1: m.r1,
+        }
+    }
+}
+
+impl<S> From<Mat4<S>> for Mat4x3<S> where S: FloatType<S> {
+    fn from(m: Mat4<S>) -> Mat4x3<S> {
+        Mat4x3 {
+            r0: m.r0,
+            r1: m.r1,
+            r2: m.r2,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//Pod and Zeroable for the #[repr(C)] types, behind the bytemuck feature, these are plain rows of S without padding,
+//so they can be handed to any graphics API as bytes, slices of them go through bytemuck::cast_slice
+
+use bytemuck::{Pod, Zeroable};
+
+use super::*;
+
+macro_rules! impl_pod {
+    ($($ty:ty),+) => {
+        $(
+            unsafe impl<S> Zeroable for $ty where S: Pod {}
+
+            unsafe impl<S> Pod for $ty where S: Pod {}
+
+            impl<S> $ty where S: Pod {
+                pub fn as_bytes(&self) -> &[u8] {
+                    bytemuck::bytes_of(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_pod!(Vec2<S>, Vec3<S>, Vec4<S>, IVec2<S>, IVec3<S>, IVec4<S>);
+impl_pod!(Mat2<S>, Mat3<S>, Mat4<S>, Mat2x3<S>, Mat2x4<S>, Mat3x2<S>, Mat3x4<S>, Mat4x2<S>, Mat4x3<S>);
+impl_pod!(Quat<S>, DualQuat<S>, Complex<S>, ga::Bivector3<S>, ga::Rotor2<S>, ga::Rotor3<S>);
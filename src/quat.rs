@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -34,6 +34,11 @@ impl<S> Quat<S> where S: FloatType<S> {
         self.w * rhs.w + self.v.dot(rhs.v)
     }
 
+    //q and -q describe the same rotation, so either one counts, every component is compared like abs_diff_eq
+    pub fn rotation_approx_eq(self, rhs: Quat<S>, epsilon: S) -> bool {
+        self.abs_diff_eq(rhs, epsilon) || self.abs_diff_eq(-rhs, epsilon)
+    }
+
     pub fn magnitude_squared(self) -> S {
         self.dot(self)
     }
@@ -49,11 +54,25 @@ impl<S> Quat<S> where S: FloatType<S> {
         }
     }
 
+    //Unchecked, the zero quaternion gives non-finite components, use try_inverse when that can happen
     pub fn inverse(self) -> Quat<S> {
-        let inv_fact = S::one() / self.magnitude();
+        let inv_fact = S::one() / self.magnitude_squared();
         self.conjugate() * inv_fact
     }
 
+    pub fn try_inverse(self) -> Option<Quat<S>> {
+        self.try_inverse_with_epsilon(S::DEF_EPSILON)
+    }
+
+    //None when the magnitude is epsilon or less
+    pub fn try_inverse_with_epsilon(self, epsilon: S) -> Option<Quat<S>> {
+        let magnitude_squared = self.magnitude_squared();
+        if !magnitude_squared.is_finite() || magnitude_squared <= epsilon * epsilon {
+            return None;
+        }
+        Some(self.conjugate() * (S::one() / magnitude_squared))
+    }
+
     pub fn normalized(self) -> Quat<S> {
         let inv = S::one() / self.magnitude();
         Quat {
@@ -92,34 +111,174 @@ impl<S> Quat<S> where S: FloatType<S> {
     }
 
 
+    //e^q, the pure part is the half angle times the axis, so exp((0, axis * theta / 2)) rotates by theta around axis
+    pub fn exp(self) -> Quat<S> {
+        let angle = self.v.length();
+        let exp_w = self.w.exp();
+        Quat {
+            w: exp_w * angle.cos(),
+            v: self.v * (exp_w * sinc(angle)),
+        }
+    }
+
+    //Natural logarithm, the inverse of exp, ln of a unit quaternion is pure (the half angle times the axis),
+    //the axis of a negative real quaternion is undefined, x is picked for those
+    pub fn ln(self) -> Quat<S> {
+        let magnitude = self.magnitude();
+        let sin_length = self.v.length();
+        let factor = if self.w > S::zero() && sin_length < get_series_threshold::<S>() * self.w {
+            //atan(s / w) / s with its Taylor series in s / w, as the division loses precision near the identity,
+            //gated on the ratio so small non-unit quaternions far from the identity take the exact path
+            let three: S = num::cast(3).unwrap();
+            let ratio = sin_length / self.w;
+            (S::one() - ratio * ratio / three) / self.w
+        } else if sin_length == S::zero() {
+            return Quat {
+                w: magnitude.ln(),
+                v: Vec3 { x: num::cast(core::f64::consts::PI).unwrap(), y: S::zero(), z: S::zero() },
+            };
+        } else {
+            sin_length.atan2(self.w) / sin_length
+        };
+        Quat {
+            w: magnitude.ln(),
+            v: self.v * factor,
+        }
+    }
+
+    //Rotates by |omega| * dt radians around the direction of omega, for an angular velocity omega in radians per second
+    pub fn from_angular_velocity(omega: Vec3<S>, dt: S) -> Quat<S> {
+        let half: S = num::cast(0.5).unwrap();
+        Quat { w: S::zero(), v: omega * (dt * half) }.exp()
+    }
+
+    //The angular velocity that takes q0 to q1 in dt seconds, going the shortest way around,
+    //so Quat::from_angular_velocity(omega, dt) * q0 == q1
+    pub fn angular_velocity_between(q0: Quat<S>, q1: Quat<S>, dt: S) -> Vec3<S> {
+        let delta = q1.normalized() * q0.normalized().conjugate();
+        let delta = if delta.w < S::zero() { -delta } else { delta };
+        delta.ln().v * ((S::one() + S::one()) / dt)
+    }
+
+    //Applies an angular velocity (in the parent space) for dt seconds, renormalizing to keep the drift out
+    pub fn integrate_angular_velocity(&mut self, omega: Vec3<S>, dt: S) {
+        *self = (Quat::from_angular_velocity(omega, dt) * *self).normalized();
+    }
+
+    //The inner control point at self for squad, from the keys before and after it, unit quaternions only
+    pub fn get_squad_control(self, prev: Quat<S>, next: Quat<S>) -> Quat<S> {
+        let quarter: S = num::cast(0.25).unwrap();
+        let prev = if self.dot(prev) < S::zero() { -prev } else { prev };
+        let next = if self.dot(next) < S::zero() { -next } else { next };
+        let inv = self.conjugate();
+        self * (((inv * next).ln() + (inv * prev).ln()) * -quarter).exp()
+    }
+
+    //Spherical quadrangle interpolation from self to other, with control points a (at self) and b (at other) from
+    //get_squad_control, consecutive segments share their tangents, so a path through several keys is smooth
+    pub fn squad(self, other: Quat<S>, a: Quat<S>, b: Quat<S>, t: S) -> Quat<S> {
+        let two = S::one() + S::one();
+        let (other, b) = if self.dot(other) < S::zero() { (-other, -b) } else { (other, b) };
+        self.slerp_no_flip(other, t).slerp_no_flip(a.slerp_no_flip(b, t), two * t * (S::one() - t))
+    }
+
     pub fn get_quat_flex_euler_deg(pitch: S, heading: S, bank: S, order: RotationOrder) -> Quat<S> {
         Quat::get_quat_flex_euler_rad(pitch.to_radians(), heading.to_radians(), bank.to_radians(), order)
     }
 
+    //Intrinsic rotations, PHB rotates around x, then around the rotated y, then around the twice rotated z,
+    //see RotationOrder for how the angles are assigned for the proper Euler orders
     pub fn get_quat_flex_euler_rad(pitch: S, heading: S, bank: S, order: RotationOrder) -> Quat<S> {
-        let two = num::cast(2).unwrap();
-        let pitch = pitch / two;
-        let heading = heading / two;
-        let bank = bank / two;
+        Quat::get_quat_from_euler_rad(order.get_ordered_angles(pitch, heading, bank), order, EulerFrame::Intrinsic)
+    }
 
-        let sp = pitch.sin();
-        let cp = pitch.cos();
-        let sh = heading.sin();
-        let ch = heading.cos();
-        let sb = bank.sin();
-        let cb = bank.cos();
+    pub fn get_quat_from_euler_deg(angles: Vec3<S>, order: RotationOrder, frame: EulerFrame) -> Quat<S> {
+        Quat::get_quat_from_euler_rad(angles * S::one().to_radians(), order, frame)
+    }
 
-        let p = Quat { w: cp, v: Vec3 { x: sp, y: S::zero(), z: S::zero() } };
-        let h = Quat { w: ch, v: Vec3 { x: S::zero(), y: sh, z: S::zero() } };
-        let b = Quat { w: cb, v: Vec3 { x: S::zero(), y: S::zero(), z: sb } };
+    //The angles are in the order of the rotations, so angles.x is the angle around the first axis of the order
+    pub fn get_quat_from_euler_rad(angles: Vec3<S>, order: RotationOrder, frame: EulerFrame) -> Quat<S> {
+        let half: S = num::cast(0.5).unwrap();
+        let axes = order.get_axes();
+        let mut q = Quat::identity();
+        for (i, &axis) in axes.iter().enumerate() {
+            let half_angle = angles[i] * half;
+            let mut v = Vec3::zero();
+            v[axis] = half_angle.sin();
+            let rotation = Quat { w: half_angle.cos(), v };
+            q = match frame {
+                EulerFrame::Intrinsic => q * rotation,
+                EulerFrame::Extrinsic => rotation * q,
+            };
+        }
+        q
+    }
+
+    pub fn get_euler_angles_deg(&self, order: RotationOrder, frame: EulerFrame) -> Vec3<S> {
+        self.get_euler_angles_rad(order, frame) * S::one().to_degrees()
+    }
+
+    //The inverse of get_quat_from_euler_rad for every order, the first and last angle are in [-pi, pi], the middle one
+    //is in [-pi/2, pi/2] for the Tait-Bryan orders and in [0, pi] for the proper Euler orders,
+    //in gimbal lock only the sum (or difference) of the first and last angle is known, then the last angle is set to 0
+    //(Bernardes and Viollet, "Quaternion to Euler angles conversion: A direct, general and computationally efficient method")
+    pub fn get_euler_angles_rad(&self, order: RotationOrder, frame: EulerFrame) -> Vec3<S> {
+        let two = S::one() + S::one();
+        let pi: S = num::cast(core::f64::consts::PI).unwrap();
+        let q = self.normalized();
 
-        match order {
-            RotationOrder::PHB => p * h * b,
-            RotationOrder::PBH => p * b * h,
-            RotationOrder::HPB => h * p * b,
-            RotationOrder::HBP => b * b * p,
-            RotationOrder::BPH => b * p * h,
-            RotationOrder::BHP => b * h * p,
+        //Intrinsic rotations are the extrinsic ones in the reverse order
+        let axes = order.get_axes();
+        let (i, j) = match frame {
+            EulerFrame::Intrinsic => (axes[2], axes[1]),
+            EulerFrame::Extrinsic => (axes[0], axes[1]),
+        };
+        let proper = order.is_proper_euler();
+        let k = 3 - i - j;
+        let sign = if (i + 1) % 3 == j { S::one() } else { -S::one() };
+
+        let (a, b, c, d) = if proper {
+            (q.w, q.v[i], q.v[j], q.v[k] * sign)
+        } else {
+            (q.w - q.v[j], q.v[i] + q.v[k] * sign, q.v[j] + q.w, q.v[k] * sign - q.v[i])
+        };
+
+        let middle = two * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+
+        let lock_epsilon = S::epsilon().sqrt();
+        let (first, last) = if middle <= lock_epsilon {
+            match frame {
+                EulerFrame::Intrinsic => (S::zero(), two * half_sum),
+                EulerFrame::Extrinsic => (two * half_sum, S::zero()),
+            }
+        } else if middle >= pi - lock_epsilon {
+            match frame {
+                EulerFrame::Intrinsic => (S::zero(), two * half_diff),
+                EulerFrame::Extrinsic => (-two * half_diff, S::zero()),
+            }
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        let (middle, last) = if proper {
+            (middle, last)
+        } else {
+            (middle - pi / two, last * sign)
+        };
+
+        let wrap = |angle: S| if angle > pi {
+            angle - two * pi
+        } else if angle < -pi {
+            angle + two * pi
+        } else {
+            angle
+        };
+
+        match frame {
+            EulerFrame::Intrinsic => Vec3 { x: wrap(last), y: middle, z: wrap(first) },
+            EulerFrame::Extrinsic => Vec3 { x: wrap(first), y: middle, z: wrap(last) },
         }
     }
 
@@ -186,7 +345,7 @@ impl<S> Quat<S> where S: FloatType<S> {
         let min_two: S = num::cast(-2).unwrap();
         let half: S = num::cast(0.5).unwrap();
         let almost_one: S = num::cast(0.9999).unwrap();
-        let half_pi: S = num::cast(std::f64::consts::FRAC_PI_2).unwrap();
+        let half_pi: S = num::cast(core::f64::consts::FRAC_PI_2).unwrap();
         let sin_pitch: S = min_two * (self.v.y * self.v.z - self.w * self.v.x);
 
         if sin_pitch.abs() > almost_one {
@@ -213,7 +372,7 @@ impl<S> Quat<S> where S: FloatType<S> {
         let min_two: S = num::cast(-2).unwrap();
         let half: S = num::cast(0.5).unwrap();
         let almost_one: S = num::cast(0.9999).unwrap();
-        let half_pi: S = num::cast(std::f64::consts::FRAC_PI_2).unwrap();
+        let half_pi: S = num::cast(core::f64::consts::FRAC_PI_2).unwrap();
         let sin_pitch: S = min_two * (self.v.y * self.v.z + self.w * self.v.x);
 
         if sin_pitch.abs() > almost_one {
@@ -257,26 +416,104 @@ impl<S> Quat<S> where S: FloatType<S> {
         }
     }
 
-    pub fn slerp(self, other: Quat<S>, t: S) -> Quat<S> {
-        let mut cos_omega = self.dot(other);
+    //The shortest rotation that takes the direction of from to the direction of to, opposite directions get a half turn
+    //around an axis perpendicular to from
+    pub fn from_rotation_arc(from: Vec3<S>, to: Vec3<S>) -> Quat<S> {
+        let from = from.normalized();
+        let to = to.normalized();
+        let w = S::one() + from.dot(to);
+        //Only exactly opposite directions leave no cross product to turn around, nearly opposite ones still have a usable one
+        if w <= S::epsilon() {
+            let other = if from.x.abs() < num::cast(0.9).unwrap() { Vec3::right() } else { Vec3::up() };
+            Quat {
+                w: S::zero(),
+                v: from.cross(other).normalized(),
+            }
+        } else {
+            Quat {
+                w,
+                v: from.cross(to),
+            }.normalized()
+        }
+    }
 
-        let q0 = self;
-        let mut q1 = other;
+    //The rotation that turns Vec3::forward() towards forward and keeps Vec3::up() as close to up as possible,
+    //when forward and up are parallel this is the shortest rotation to forward
+    pub fn look_rotation(forward: Vec3<S>, up: Vec3<S>) -> Quat<S> {
+        let forward = forward.normalized();
+        let right = up.cross(forward);
+        if right.length_squared() < num::cast(0.000001).unwrap() {
+            return Quat::from_rotation_arc(Vec3::forward(), forward);
+        }
+        let right = right.normalized();
+        //Mat3::from(q) has the rotated axes as its rows, so this is its inverse
+        Quat::from(Mat3::new_from_vec3s(right, forward.cross(right), forward)).normalized()
+    }
+
+    //Splits the rotation up into a twist around the unit axis and a swing that moves the axis, self == swing * twist,
+    //a swing of half a turn leaves no twist to speak of, the twist is the identity then
+    pub fn get_swing_twist(&self, axis: Vec3<S>) -> (Quat<S>, Quat<S>) {
+        debug_assert!(axis.is_unit());
+        let projected = Quat { w: self.w, v: axis * self.v.dot(axis) };
+        let twist = if projected.magnitude_squared() < num::cast(0.00000001).unwrap() {
+            Quat::identity()
+        } else {
+            projected.normalized()
+        };
+        (*self * twist.conjugate(), twist)
+    }
 
-        if cos_omega < S::zero() {
-            q1 = -q1;
-            cos_omega = -cos_omega;
+    //The rotation closest to the matrix, unlike Quat::from this also works for matrices with scale, shear or numerical drift,
+    //it iteratively turns the rotation towards the axes of the matrix
+    //(Müller et al., "A Robust Method to Extract the Rotational Part of Deformations")
+    pub fn from_mat3(m: Mat3<S>) -> Quat<S> {
+        let guess = Quat::from(m);
+        let mut q = if guess.magnitude_squared().is_finite() && guess.magnitude_squared() > S::zero() {
+            guess.normalized()
+        } else {
+            Quat::identity()
+        };
+
+        let tiny: S = num::cast(1e-9).unwrap();
+        for _ in 0..32 {
+            //The rows of m are the rotated axes, see From<Quat> for Mat3
+            let mut torque = Vec3::zero();
+            let mut alignment = S::zero();
+            for i in 0..3 {
+                let mut axis = Vec3::zero();
+                axis[i] = S::one();
+                let rotated = q * axis;
+                torque += rotated.cross(m[i]);
+                alignment = alignment + rotated.dot(m[i]);
+            }
+            let omega = torque / (alignment.abs() + tiny);
+            let angle = omega.length();
+            if angle < S::epsilon() {
+                break;
+            }
+            q = (Quat::get_quat_from_angle_axis(angle, omega / angle) * q).normalized();
         }
+        q
+    }
 
-        //let mut k0 = S::zero();
-        //let mut k1 = S::zero();
+    pub fn slerp(self, other: Quat<S>, t: S) -> Quat<S> {
+        if self.dot(other) < S::zero() {
+            self.slerp_no_flip(-other, t)
+        } else {
+            self.slerp_no_flip(other, t)
+        }
+    }
+
+    //Slerp without taking the shortest way around, which squad needs to stay continuous
+    fn slerp_no_flip(self, other: Quat<S>, t: S) -> Quat<S> {
+        let cos_omega = self.dot(other);
 
         if cos_omega > num::cast(0.9999).unwrap() {
             let k0 = S::one() - t;
             let k1 = t;
             Quat {
-                w: q0.w * k0 + q1.w * k1,
-                v: q0.v * k0 + q1.v * k1,
+                w: self.w * k0 + other.w * k1,
+                v: self.v * k0 + other.v * k1,
             }
         } else {
             let sin_omega = (S::one() - cos_omega * cos_omega).sqrt();
@@ -288,8 +525,8 @@ impl<S> Quat<S> where S: FloatType<S> {
             let k0 = ((S::one() - t) * omega).sin() * one_over_sin_omega;
             let k1 = (t * omega).sin() * one_over_sin_omega;
             Quat {
-                w: q0.w * k0 + q1.w * k1,
-                v: q0.v * k0 + q1.v * k1,
+                w: self.w * k0 + other.w * k1,
+                v: self.v * k0 + other.v * k1,
             }
         }
     }
@@ -313,14 +550,46 @@ impl<S> Quat<S> where S: FloatType<S> {
     pub fn rotate_around(&mut self, theta: S, n: Vec3<S>) {
         *self *= Quat::get_quat_from_angle_axis(theta, n);
     }
+
+    //Bulk rotations, the quaternion is turned into a matrix once, which is a lot cheaper per vector than q v q⁻¹,
+    //a rotation does not change the length, so points, vectors and (unit) normals are all rotated the same way
+    pub fn transform_points(&self, points: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        self.get_rotation_mat().transform_vectors(points, out);
+    }
+
+    pub fn transform_points_in_place(&self, points: &mut [Vec3<S>]) {
+        self.get_rotation_mat().transform_vectors_in_place(points);
+    }
+
+    pub fn transform_vectors(&self, vectors: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        self.get_rotation_mat().transform_vectors(vectors, out);
+    }
+
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vec3<S>]) {
+        self.get_rotation_mat().transform_vectors_in_place(vectors);
+    }
+
+    pub fn transform_normals(&self, normals: &[Vec3<S>], out: &mut [Vec3<S>]) {
+        self.get_rotation_mat().transform_vectors(normals, out);
+    }
+
+    pub fn transform_normals_in_place(&self, normals: &mut [Vec3<S>]) {
+        self.get_rotation_mat().transform_vectors_in_place(normals);
+    }
+
+    //The matrix that does the same as q * v, which is the transpose of Mat3::from(q)
+    fn get_rotation_mat(&self) -> Mat3<S> {
+        Mat3::from(self.normalized()).transpose()
+    }
 }
 
 
+//Checked in every build, panics where try_inverse gives None, inverse is the unchecked version
 impl<S> Not for Quat<S> where S: FloatType<S> {
     type Output = Quat<S>;
 
     fn not(self) -> Self::Output {
-        self.inverse()
+        self.try_inverse().expect("Tried to invert a zero Quat")
     }
 }
 
@@ -336,17 +605,48 @@ impl<S> Neg for Quat<S> where S: FloatType<S> {
 }
 
 
-impl<S> Mul<Quat<S>> for Quat<S> where S: FloatType<S> {
+impl<S> Add<Quat<S>> for Quat<S> where S: FloatType<S> {
     type Output = Quat<S>;
 
-    fn mul(self, rhs: Quat<S>) -> Quat<S> {
+    fn add(self, rhs: Quat<S>) -> Self::Output {
         Quat {
-            w: rhs.w * self.w - self.v.dot(rhs.v),
-            v: rhs.v * self.w + self.v * rhs.w + self.v.cross(rhs.v),
+            w: self.w + rhs.w,
+            v: self.v + rhs.v,
         }
     }
 }
 
+impl<S> AddAssign<Quat<S>> for Quat<S> where S: FloatType<S> {
+    fn add_assign(&mut self, rhs: Quat<S>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S> Sub<Quat<S>> for Quat<S> where S: FloatType<S> {
+    type Output = Quat<S>;
+
+    fn sub(self, rhs: Quat<S>) -> Self::Output {
+        Quat {
+            w: self.w - rhs.w,
+            v: self.v - rhs.v,
+        }
+    }
+}
+
+impl<S> SubAssign<Quat<S>> for Quat<S> where S: FloatType<S> {
+    fn sub_assign(&mut self, rhs: Quat<S>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<S> Mul<Quat<S>> for Quat<S> where S: FloatType<S> {
+    type Output = Quat<S>;
+
+    fn mul(self, rhs: Quat<S>) -> Quat<S> {
+        simd::quat_mul(self, rhs)
+    }
+}
+
 impl<S> Mul<Vec3<S>> for Quat<S> where S: FloatType<S> {
     type Output = Vec3<S>;
 
@@ -385,11 +685,12 @@ impl<S> MulAssign<S> for Quat<S> where S: FloatType<S> {
     }
 }
 
+//Multiplies by the inverse of rhs, which works the same way as the Not operator on the zero quaternion
 impl<S> Div<Quat<S>> for Quat<S> where S: FloatType<S> {
     type Output = Quat<S>;
 
     fn div(self, rhs: Quat<S>) -> Self::Output {
-        self * rhs.inverse()
+        self * !rhs
     }
 }
 
@@ -518,8 +819,33 @@ impl<S> From<Mat3<S>> for Quat<S> where S: FloatType<S> {
 }
 
 
+//The quaternion that rotates vectors the same way as the rotor
+impl<S> From<ga::Rotor3<S>> for Quat<S> where S: FloatType<S> {
+    fn from(r: ga::Rotor3<S>) -> Quat<S> {
+        Quat {
+            w: r.s,
+            v: -r.b.get_normal(),
+        }
+    }
+}
+
 impl<S> Default for Quat<S> where S: FloatType<S> {
     fn default() -> Quat<S> {
         Quat::identity()
     }
 }
+
+//sin(x) / x, with its Taylor series near 0, where the division loses precision
+fn sinc<S>(x: S) -> S where S: FloatType<S> {
+    if x.abs() < get_series_threshold() {
+        let six: S = num::cast(6).unwrap();
+        S::one() - x * x / six
+    } else {
+        x.sin() / x
+    }
+}
+
+//Below this the second order Taylor series are exact up to the precision of S
+fn get_series_threshold<S>() -> S where S: FloatType<S> {
+    S::epsilon().sqrt().sqrt()
+}
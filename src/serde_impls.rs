@@ -0,0 +1,64 @@
+//Serialize and Deserialize for the vectors, matrices and quaternions, behind the serde feature,
+//everything is stored as (nested) arrays to keep scene and animation files compact,
+//vectors as [x, y, z], matrices as their rows [[m00, m01], [m10, m11]] and quaternions as [w, x, y, z]
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::*;
+
+macro_rules! impl_serde_vec {
+    ($vec:ident, $bound:path, $n:expr, [$($index:literal => $field:ident),+]) => {
+        impl<S> Serialize for $vec<S> where S: $bound + Serialize {
+            fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error> where T: Serializer {
+                [$(self.$field),+].serialize(serializer)
+            }
+        }
+
+        impl<'de, S> Deserialize<'de> for $vec<S> where S: $bound + Deserialize<'de> {
+            fn deserialize<D>(deserializer: D) -> Result<$vec<S>, D::Error> where D: Deserializer<'de> {
+                let arr = <[S; $n]>::deserialize(deserializer)?;
+                Ok($vec { $($field: arr[$index]),+ })
+            }
+        }
+    };
+}
+
+macro_rules! impl_serde_mat {
+    ($mat:ident, $vec:ident, $n:expr, [$($row:ident),+]) => {
+        impl<S> Serialize for $mat<S> where S: FloatType<S> + Serialize {
+            fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error> where T: Serializer {
+                [$(self.$row),+].serialize(serializer)
+            }
+        }
+
+        impl<'de, S> Deserialize<'de> for $mat<S> where S: FloatType<S> + Deserialize<'de> {
+            fn deserialize<D>(deserializer: D) -> Result<$mat<S>, D::Error> where D: Deserializer<'de> {
+                let [$($row),+] = <[$vec<S>; $n]>::deserialize(deserializer)?;
+                Ok($mat { $($row),+ })
+            }
+        }
+    };
+}
+
+impl_serde_vec!(Vec2, FloatType<S>, 2, [0 => x, 1 => y]);
+impl_serde_vec!(Vec3, FloatType<S>, 3, [0 => x, 1 => y, 2 => z]);
+impl_serde_vec!(Vec4, FloatType<S>, 4, [0 => x, 1 => y, 2 => z, 3 => w]);
+impl_serde_vec!(IVec2, IntegerType, 2, [0 => x, 1 => y]);
+impl_serde_vec!(IVec3, IntegerType, 3, [0 => x, 1 => y, 2 => z]);
+impl_serde_vec!(IVec4, IntegerType, 4, [0 => x, 1 => y, 2 => z, 3 => w]);
+
+impl_serde_mat!(Mat2, Vec2, 2, [r0, r1]);
+impl_serde_mat!(Mat3, Vec3, 3, [r0, r1, r2]);
+impl_serde_mat!(Mat4, Vec4, 4, [r0, r1, r2, r3]);
+
+impl<S> Serialize for Quat<S> where S: FloatType<S> + Serialize {
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error> where T: Serializer {
+        [self.w, self.v.x, self.v.y, self.v.z].serialize(serializer)
+    }
+}
+
+impl<'de, S> Deserialize<'de> for Quat<S> where S: FloatType<S> + Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Quat<S>, D::Error> where D: Deserializer<'de> {
+        Ok(Quat::from(<[S; 4]>::deserialize(deserializer)?))
+    }
+}
@@ -0,0 +1,81 @@
+//The hot paths behind Mat4 * Mat4, Mat4 * Vec4, Mat4::transpose, Mat4::inverse and Quat * Quat,
+//every FloatType goes through the functions below, which pick the SSE2 versions (in sse2) for f32 on x86 and x86_64
+//and the generic scalar versions (in scalar) for everything else, so Mat4n and Quatn get faster without any changes,
+//the choice is a TypeId comparison that is resolved at compile time,
+//Mat4 * Mat4 does two rows at a time with AVX, but only when the crate is compiled with AVX enabled
+//(RUSTFLAGS="-C target-feature=+avx", or a target-cpu that has it), there is no runtime detection
+
+use super::*;
+
+pub mod scalar;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+mod sse2;
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+#[inline(always)]
+fn is_f32<S>() -> bool where S: FloatType<S> {
+    core::any::TypeId::of::<S>() == core::any::TypeId::of::<f32>()
+}
+
+//Reinterprets a value as the same type spelled differently, T and U have to be the same type (checked with is_f32)
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+#[inline(always)]
+unsafe fn same_type<T, U>(value: &T) -> U where T: Copy, U: Copy {
+    debug_assert!(core::mem::size_of::<T>() == core::mem::size_of::<U>());
+    core::mem::transmute_copy(value)
+}
+
+#[inline]
+pub(crate) fn mat4_mul<S>(lhs: &Mat4<S>, rhs: &Mat4<S>) -> Mat4<S> where S: FloatType<S> {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    {
+        if is_f32::<S>() {
+            return unsafe { same_type(&sse2::mat4_mul(&same_type(lhs), &same_type(rhs))) };
+        }
+    }
+    scalar::mat4_mul(lhs, rhs)
+}
+
+#[inline]
+pub(crate) fn mat4_mul_vec4<S>(m: &Mat4<S>, v: Vec4<S>) -> Vec4<S> where S: FloatType<S> {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    {
+        if is_f32::<S>() {
+            return unsafe { same_type(&sse2::mat4_mul_vec4(&same_type(m), same_type(&v))) };
+        }
+    }
+    scalar::mat4_mul_vec4(m, v)
+}
+
+#[inline]
+pub(crate) fn mat4_transpose<S>(m: &Mat4<S>) -> Mat4<S> where S: FloatType<S> {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    {
+        if is_f32::<S>() {
+            return unsafe { same_type(&sse2::mat4_transpose(&same_type(m))) };
+        }
+    }
+    scalar::mat4_transpose(m)
+}
+
+#[inline]
+pub(crate) fn mat4_inverse<S>(m: &Mat4<S>) -> Mat4<S> where S: FloatType<S> {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    {
+        if is_f32::<S>() {
+            return unsafe { same_type(&sse2::mat4_inverse(&same_type(m))) };
+        }
+    }
+    scalar::mat4_inverse(m)
+}
+
+#[inline]
+pub(crate) fn quat_mul<S>(lhs: Quat<S>, rhs: Quat<S>) -> Quat<S> where S: FloatType<S> {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    {
+        if is_f32::<S>() {
+            return unsafe { same_type(&sse2::quat_mul(same_type(&lhs), same_type(&rhs))) };
+        }
+    }
+    scalar::quat_mul(lhs, rhs)
+}
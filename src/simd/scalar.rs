@@ -0,0 +1,97 @@
+//The generic implementations, these are what every type other than f32 (and f32 without SSE2) uses, and what the SIMD paths are checked against
+
+use super::*;
+
+pub fn mat4_mul<S>(lhs: &Mat4<S>, rhs: &Mat4<S>) -> Mat4<S> where S: FloatType<S> {
+    let rhs = mat4_transpose(rhs);
+    Mat4 {
+        r0: Vec4 { x: lhs[0].dot(rhs[0]), y: lhs[0].dot(rhs[1]), z: lhs[0].dot(rhs[2]), w: lhs[0].dot(rhs[3]) },
+        r1: Vec4 { x: lhs[1].dot(rhs[0]), y: lhs[1].dot(rhs[1]), z: lhs[1].dot(rhs[2]), w: lhs[1].dot(rhs[3]) },
+        r2: Vec4 { x: lhs[2].dot(rhs[0]), y: lhs[2].dot(rhs[1]), z: lhs[2].dot(rhs[2]), w: lhs[2].dot(rhs[3]) },
+        r3: Vec4 { x: lhs[3].dot(rhs[0]), y: lhs[3].dot(rhs[1]), z: lhs[3].dot(rhs[2]), w: lhs[3].dot(rhs[3]) },
+    }
+}
+
+pub fn mat4_mul_vec4<S>(m: &Mat4<S>, v: Vec4<S>) -> Vec4<S> where S: FloatType<S> {
+    Vec4 {
+        x: m.r0.dot(v),
+        y: m.r1.dot(v),
+        z: m.r2.dot(v),
+        w: m.r3.dot(v),
+    }
+}
+
+pub fn mat4_transpose<S>(m: &Mat4<S>) -> Mat4<S> where S: FloatType<S> {
+    Mat4 {
+        r0: Vec4 { x: m[0][0], y: m[1][0], z: m[2][0], w: m[3][0] },
+        r1: Vec4 { x: m[0][1], y: m[1][1], z: m[2][1], w: m[3][1] },
+        r2: Vec4 { x: m[0][2], y: m[1][2], z: m[2][2], w: m[3][2] },
+        r3: Vec4 { x: m[0][3], y: m[1][3], z: m[2][3], w: m[3][3] },
+    }
+}
+
+//This version does not use the adjoint and determinant functions, because they share a bunch of calculations
+//that are best left un-abstracted for matrices of this size and up (for reduced memory usage, less redundant computation and potential compiler optimizations)
+pub fn mat4_inverse<S>(m: &Mat4<S>) -> Mat4<S> where S: FloatType<S> {
+    //Pre-calculating sub-factors, since all of them are used 4 times
+    let sf00 = m[2][2] * m[3][3] - m[3][2] * m[2][3];
+    let sf01 = m[2][1] * m[3][3] - m[3][1] * m[2][3];
+    let sf02 = m[2][1] * m[3][2] - m[3][1] * m[2][2];
+    let sf03 = m[2][0] * m[3][3] - m[3][0] * m[2][3];
+    let sf04 = m[2][0] * m[3][2] - m[3][0] * m[2][2];
+    let sf05 = m[2][0] * m[3][1] - m[3][0] * m[2][1];
+    let sf06 = m[1][2] * m[3][3] - m[3][2] * m[1][3];
+    let sf07 = m[1][1] * m[3][3] - m[3][1] * m[1][3];
+    let sf08 = m[1][1] * m[3][2] - m[3][1] * m[1][2];
+    let sf09 = m[1][0] * m[3][3] - m[3][0] * m[1][3];
+    let sf10 = m[1][0] * m[3][2] - m[3][0] * m[1][2];
+    let sf11 = m[1][0] * m[3][1] - m[3][0] * m[1][1];
+    let sf12 = m[1][2] * m[2][3] - m[2][2] * m[1][3];
+    let sf13 = m[1][1] * m[2][3] - m[2][1] * m[1][3];
+    let sf14 = m[1][1] * m[2][2] - m[2][1] * m[1][2];
+    let sf15 = m[1][0] * m[2][3] - m[2][0] * m[1][3];
+    let sf16 = m[1][0] * m[2][2] - m[2][0] * m[1][2];
+    let sf17 = m[1][0] * m[2][1] - m[2][0] * m[1][1];
+
+
+    let r0 = Vec4 {
+        x: m[1][1] * sf00 - m[1][2] * sf01 + m[1][3] * sf02,
+        y: -(m[0][1] * sf00 - m[0][2] * sf01 + m[0][3] * sf02),
+        z: m[0][1] * sf06 - m[0][2] * sf07 + m[0][3] * sf08,
+        w: -(m[0][1] * sf12 - m[0][2] * sf13 + m[0][3] * sf14),
+    };
+
+    let r1 = Vec4 {
+        x: -(m[1][0] * sf00 - m[1][2] * sf03 + m[1][3] * sf04),
+        y: m[0][0] * sf00 - m[0][2] * sf03 + m[0][3] * sf04,
+        z: -(m[0][0] * sf06 - m[0][2] * sf09 + m[0][3] * sf10),
+        w: m[0][0] * sf12 - m[0][2] * sf15 + m[0][3] * sf16,
+    };
+
+    let r2 = Vec4 {
+        x: m[1][0] * sf01 - m[1][1] * sf03 + m[1][3] * sf05,
+        y: -(m[0][0] * sf01 - m[0][1] * sf03 + m[0][3] * sf05),
+        z: m[0][0] * sf07 - m[0][1] * sf09 + m[0][3] * sf11,
+        w: -(m[0][0] * sf13 - m[0][1] * sf15 + m[0][3] * sf17),
+    };
+
+    let r3 = Vec4 {
+        x: -(m[1][0] * sf02 - m[1][1] * sf04 + m[1][2] * sf05),
+        y: m[0][0] * sf02 - m[0][1] * sf04 + m[0][2] * sf05,
+        z: -(m[0][0] * sf08 - m[0][1] * sf10 + m[0][2] * sf11),
+        w: m[0][0] * sf14 - m[0][1] * sf16 + m[0][2] * sf17,
+    };
+
+    let adj = Mat4 { r0, r1, r2, r3 };
+
+    let det = m[0][0] * adj[0][0] + m[1][0] * adj[0][1] + m[2][0] * adj[0][2] + m[3][0] * adj[0][3];
+
+    adj / det
+}
+
+pub fn quat_mul<S>(lhs: Quat<S>, rhs: Quat<S>) -> Quat<S> where S: FloatType<S> {
+    Quat {
+        w: rhs.w * lhs.w - lhs.v.dot(rhs.v),
+        v: rhs.v * lhs.w + lhs.v * rhs.w + lhs.v.cross(rhs.v),
+    }
+}
@@ -0,0 +1,188 @@
+//SSE2 versions of the f32 hot paths, Vec4<f32>, Mat4<f32> (4 rows of Vec4) and Quat<f32> (w, then x y z) are #[repr(C)],
+//so their rows load straight into __m128 registers, all of these are unsafe only because of the intrinsics,
+//SSE2 is guaranteed by the cfg on this module
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::*;
+
+//_mm_shuffle_ps with the lane indices written out, the first 2 lanes come from a, the last 2 from b
+macro_rules! shuffle {
+    ($a:expr, $b:expr, $x:literal, $y:literal, $z:literal, $w:literal) => {
+        _mm_shuffle_ps::<{ $x | ($y << 2) | ($z << 4) | ($w << 6) }>($a, $b)
+    };
+    ($a:expr, $x:literal, $y:literal, $z:literal, $w:literal) => {{
+        let a = $a;
+        shuffle!(a, a, $x, $y, $z, $w)
+    }};
+}
+
+#[inline(always)]
+unsafe fn load(v: &Vec4<f32>) -> __m128 {
+    _mm_loadu_ps(v as *const Vec4<f32> as *const f32)
+}
+
+#[inline(always)]
+unsafe fn store(v: __m128) -> Vec4<f32> {
+    let mut out = Vec4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
+    _mm_storeu_ps(&mut out as *mut Vec4<f32> as *mut f32, v);
+    out
+}
+
+#[inline(always)]
+unsafe fn load_mat(m: &Mat4<f32>) -> [__m128; 4] {
+    [load(&m.r0), load(&m.r1), load(&m.r2), load(&m.r3)]
+}
+
+#[inline(always)]
+unsafe fn store_mat(rows: [__m128; 4]) -> Mat4<f32> {
+    Mat4 {
+        r0: store(rows[0]),
+        r1: store(rows[1]),
+        r2: store(rows[2]),
+        r3: store(rows[3]),
+    }
+}
+
+//Every row of the product is a combination of the rows of rhs, weighted by that row of lhs, so rhs does not need to be transposed
+#[cfg(not(target_feature = "avx"))]
+#[inline]
+pub unsafe fn mat4_mul(lhs: &Mat4<f32>, rhs: &Mat4<f32>) -> Mat4<f32> {
+    let b = load_mat(rhs);
+    let [a0, a1, a2, a3] = load_mat(lhs);
+    store_mat([combine_rows(a0, &b), combine_rows(a1, &b), combine_rows(a2, &b), combine_rows(a3, &b)])
+}
+
+#[cfg(not(target_feature = "avx"))]
+#[inline(always)]
+unsafe fn combine_rows(a: __m128, b: &[__m128; 4]) -> __m128 {
+    let xy = _mm_add_ps(_mm_mul_ps(shuffle!(a, 0, 0, 0, 0), b[0]), _mm_mul_ps(shuffle!(a, 1, 1, 1, 1), b[1]));
+    let zw = _mm_add_ps(_mm_mul_ps(shuffle!(a, 2, 2, 2, 2), b[2]), _mm_mul_ps(shuffle!(a, 3, 3, 3, 3), b[3]));
+    _mm_add_ps(xy, zw)
+}
+
+//The same, with 2 rows of lhs in each 256 bit register, the shuffles stay within their 128 bit halves
+#[cfg(target_feature = "avx")]
+#[inline]
+pub unsafe fn mat4_mul(lhs: &Mat4<f32>, rhs: &Mat4<f32>) -> Mat4<f32> {
+    let b = load_mat(rhs);
+    let (b0, b1, b2, b3) = (_mm256_broadcast_ps(&b[0]), _mm256_broadcast_ps(&b[1]), _mm256_broadcast_ps(&b[2]), _mm256_broadcast_ps(&b[3]));
+    let lhs = lhs as *const Mat4<f32> as *const f32;
+    let mut out = Mat4 { r0: Vec4::zero(), r1: Vec4::zero(), r2: Vec4::zero(), r3: Vec4::zero() };
+    let out_ptr = &mut out as *mut Mat4<f32> as *mut f32;
+    for offset in [0, 8].iter() {
+        let a = _mm256_loadu_ps(lhs.add(*offset));
+        let xy = _mm256_add_ps(_mm256_mul_ps(_mm256_shuffle_ps::<0x00>(a, a), b0), _mm256_mul_ps(_mm256_shuffle_ps::<0x55>(a, a), b1));
+        let zw = _mm256_add_ps(_mm256_mul_ps(_mm256_shuffle_ps::<0xAA>(a, a), b2), _mm256_mul_ps(_mm256_shuffle_ps::<0xFF>(a, a), b3));
+        _mm256_storeu_ps(out_ptr.add(*offset), _mm256_add_ps(xy, zw));
+    }
+    out
+}
+
+//The 4 dot products are summed in a transposed order, so all of them end up in one register
+#[inline]
+pub unsafe fn mat4_mul_vec4(m: &Mat4<f32>, v: Vec4<f32>) -> Vec4<f32> {
+    let v = load(&v);
+    let [r0, r1, r2, r3] = load_mat(m);
+    let p0 = _mm_mul_ps(r0, v);
+    let p1 = _mm_mul_ps(r1, v);
+    let p2 = _mm_mul_ps(r2, v);
+    let p3 = _mm_mul_ps(r3, v);
+    //(p0x + p0z, p1x + p1z, p0y + p0w, p1y + p1w) and the same for p2 and p3
+    let s01 = _mm_add_ps(_mm_unpacklo_ps(p0, p1), _mm_unpackhi_ps(p0, p1));
+    let s23 = _mm_add_ps(_mm_unpacklo_ps(p2, p3), _mm_unpackhi_ps(p2, p3));
+    store(_mm_add_ps(_mm_movelh_ps(s01, s23), _mm_movehl_ps(s23, s01)))
+}
+
+#[inline]
+pub unsafe fn mat4_transpose(m: &Mat4<f32>) -> Mat4<f32> {
+    store_mat(transpose(load_mat(m)))
+}
+
+#[inline(always)]
+unsafe fn transpose([r0, r1, r2, r3]: [__m128; 4]) -> [__m128; 4] {
+    let t0 = _mm_unpacklo_ps(r0, r1);
+    let t1 = _mm_unpacklo_ps(r2, r3);
+    let t2 = _mm_unpackhi_ps(r0, r1);
+    let t3 = _mm_unpackhi_ps(r2, r3);
+    [_mm_movelh_ps(t0, t1), _mm_movehl_ps(t1, t0), _mm_movelh_ps(t2, t3), _mm_movehl_ps(t3, t2)]
+}
+
+//2x2 matrices packed as (m00, m01, m10, m11), a * b
+#[inline(always)]
+unsafe fn mat2_mul(a: __m128, b: __m128) -> __m128 {
+    _mm_add_ps(_mm_mul_ps(a, shuffle!(b, 0, 3, 0, 3)), _mm_mul_ps(shuffle!(a, 1, 0, 3, 2), shuffle!(b, 2, 1, 2, 1)))
+}
+
+//adj(a) * b
+#[inline(always)]
+unsafe fn mat2_adj_mul(a: __m128, b: __m128) -> __m128 {
+    _mm_sub_ps(_mm_mul_ps(shuffle!(a, 3, 3, 0, 0), b), _mm_mul_ps(shuffle!(a, 1, 1, 2, 2), shuffle!(b, 2, 3, 0, 1)))
+}
+
+//a * adj(b)
+#[inline(always)]
+unsafe fn mat2_mul_adj(a: __m128, b: __m128) -> __m128 {
+    _mm_sub_ps(_mm_mul_ps(a, shuffle!(b, 3, 0, 3, 0)), _mm_mul_ps(shuffle!(a, 1, 0, 3, 2), shuffle!(b, 2, 1, 2, 1)))
+}
+
+//Blockwise inverse, the matrix is split into the 2x2 blocks | A B |
+//                                                          | C D |
+//and the inverse is built from their adjugates and determinants, which needs a lot fewer shuffles than the cofactor expansion
+#[inline]
+pub unsafe fn mat4_inverse(m: &Mat4<f32>) -> Mat4<f32> {
+    let [r0, r1, r2, r3] = load_mat(m);
+    let a = _mm_movelh_ps(r0, r1);
+    let b = _mm_movehl_ps(r1, r0);
+    let c = _mm_movelh_ps(r2, r3);
+    let d = _mm_movehl_ps(r3, r2);
+
+    //The determinants of the blocks, (|A|, |B|, |C|, |D|)
+    let det_sub = _mm_sub_ps(
+        _mm_mul_ps(shuffle!(r0, r2, 0, 2, 0, 2), shuffle!(r1, r3, 1, 3, 1, 3)),
+        _mm_mul_ps(shuffle!(r0, r2, 1, 3, 1, 3), shuffle!(r1, r3, 0, 2, 0, 2)),
+    );
+    let det_a = shuffle!(det_sub, 0, 0, 0, 0);
+    let det_b = shuffle!(det_sub, 1, 1, 1, 1);
+    let det_c = shuffle!(det_sub, 2, 2, 2, 2);
+    let det_d = shuffle!(det_sub, 3, 3, 3, 3);
+
+    let d_c = mat2_adj_mul(d, c);
+    let a_b = mat2_adj_mul(a, b);
+    let x = _mm_sub_ps(_mm_mul_ps(det_d, a), mat2_mul(b, d_c));
+    let w = _mm_sub_ps(_mm_mul_ps(det_a, d), mat2_mul(c, a_b));
+    let y = _mm_sub_ps(_mm_mul_ps(det_b, c), mat2_mul_adj(d, a_b));
+    let z = _mm_sub_ps(_mm_mul_ps(det_c, b), mat2_mul_adj(a, d_c));
+
+    //|M| = |A| |D| + |B| |C| - tr(adj(A) B adj(D) C)
+    let tr = _mm_mul_ps(a_b, shuffle!(d_c, 0, 2, 1, 3));
+    let tr = _mm_add_ps(tr, shuffle!(tr, 2, 3, 0, 1));
+    let tr = _mm_add_ps(tr, shuffle!(tr, 1, 0, 3, 2));
+    let det = _mm_sub_ps(_mm_add_ps(_mm_mul_ps(det_a, det_d), _mm_mul_ps(det_b, det_c)), tr);
+
+    let inv_det = _mm_div_ps(_mm_setr_ps(1.0, -1.0, -1.0, 1.0), det);
+    let x = _mm_mul_ps(x, inv_det);
+    let y = _mm_mul_ps(y, inv_det);
+    let z = _mm_mul_ps(z, inv_det);
+    let w = _mm_mul_ps(w, inv_det);
+
+    //The blocks still need their adjugates taken, which is folded into putting them back into rows
+    store_mat([shuffle!(x, y, 3, 1, 3, 1), shuffle!(x, y, 2, 0, 2, 0), shuffle!(z, w, 3, 1, 3, 1), shuffle!(z, w, 2, 0, 2, 0)])
+}
+
+//The Hamilton product, with both quaternions as (w, x, y, z), every lane of lhs scales a shuffled and sign flipped rhs
+#[inline]
+pub unsafe fn quat_mul(lhs: Quat<f32>, rhs: Quat<f32>) -> Quat<f32> {
+    let a = _mm_loadu_ps(&lhs as *const Quat<f32> as *const f32);
+    let b = _mm_loadu_ps(&rhs as *const Quat<f32> as *const f32);
+    let w = _mm_mul_ps(shuffle!(a, 0, 0, 0, 0), b);
+    let x = _mm_mul_ps(_mm_mul_ps(shuffle!(a, 1, 1, 1, 1), shuffle!(b, 1, 0, 3, 2)), _mm_setr_ps(-1.0, 1.0, -1.0, 1.0));
+    let y = _mm_mul_ps(_mm_mul_ps(shuffle!(a, 2, 2, 2, 2), shuffle!(b, 2, 3, 0, 1)), _mm_setr_ps(-1.0, 1.0, 1.0, -1.0));
+    let z = _mm_mul_ps(_mm_mul_ps(shuffle!(a, 3, 3, 3, 3), shuffle!(b, 3, 2, 1, 0)), _mm_setr_ps(-1.0, -1.0, 1.0, 1.0));
+    let mut out = Quat { w: 0.0, v: Vec3 { x: 0.0, y: 0.0, z: 0.0 } };
+    _mm_storeu_ps(&mut out as *mut Quat<f32> as *mut f32, _mm_add_ps(_mm_add_ps(w, x), _mm_add_ps(y, z)));
+    out
+}
@@ -0,0 +1,258 @@
+use core::ops::*;
+
+use super::*;
+
+macro_rules! vec3_lanes {
+    ($name:ident, $n:expr) => {
+        #[repr(C)]
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name<S> {
+            pub x: [S; $n],
+            pub y: [S; $n],
+            pub z: [S; $n],
+        }
+
+        impl<S> $name<S> where S: FloatType<S> {
+            pub fn zero() -> $name<S> {
+                $name::splat(Vec3::zero())
+            }
+
+            //The same vector in every lane
+            pub fn splat(v: Vec3<S>) -> $name<S> {
+                $name {
+                    x: [v.x; $n],
+                    y: [v.y; $n],
+                    z: [v.z; $n],
+                }
+            }
+
+            pub fn lanes() -> usize {
+                $n
+            }
+
+            //Takes up to the first N vectors, the lanes past the end of a shorter slice are zero
+            pub fn load(vectors: &[Vec3<S>]) -> $name<S> {
+                let mut out = $name::zero();
+                for (i, v) in vectors.iter().take($n).enumerate() {
+                    out.set(i, *v);
+                }
+                out
+            }
+
+            //The xyz of up to the first N vectors, w is dropped
+            pub fn load_vec4(vectors: &[Vec4<S>]) -> $name<S> {
+                let mut out = $name::zero();
+                for (i, v) in vectors.iter().take($n).enumerate() {
+                    out.set(i, Vec3 { x: v.x, y: v.y, z: v.z });
+                }
+                out
+            }
+
+            //Writes as many lanes as fit into the slice
+            pub fn store(&self, vectors: &mut [Vec3<S>]) {
+                for (i, v) in vectors.iter_mut().take($n).enumerate() {
+                    *v = self.get(i);
+                }
+            }
+
+            //Writes the lanes into xyz, w is left alone
+            pub fn store_vec4(&self, vectors: &mut [Vec4<S>]) {
+                for (i, v) in vectors.iter_mut().take($n).enumerate() {
+                    v.x = self.x[i];
+                    v.y = self.y[i];
+                    v.z = self.z[i];
+                }
+            }
+
+            pub fn get(&self, lane: usize) -> Vec3<S> {
+                Vec3 { x: self.x[lane], y: self.y[lane], z: self.z[lane] }
+            }
+
+            pub fn set(&mut self, lane: usize, v: Vec3<S>) {
+                self.x[lane] = v.x;
+                self.y[lane] = v.y;
+                self.z[lane] = v.z;
+            }
+
+            pub fn dot(&self, rhs: &$name<S>) -> [S; $n] {
+                let mut out = [S::zero(); $n];
+                for i in 0..$n {
+                    out[i] = self.x[i] * rhs.x[i] + self.y[i] * rhs.y[i] + self.z[i] * rhs.z[i];
+                }
+                out
+            }
+
+            pub fn cross(&self, rhs: &$name<S>) -> $name<S> {
+                let mut out = $name::zero();
+                for i in 0..$n {
+                    out.x[i] = self.y[i] * rhs.z[i] - self.z[i] * rhs.y[i];
+                    out.y[i] = self.z[i] * rhs.x[i] - self.x[i] * rhs.z[i];
+                    out.z[i] = self.x[i] * rhs.y[i] - self.y[i] * rhs.x[i];
+                }
+                out
+            }
+
+            pub fn length_squared(&self) -> [S; $n] {
+                self.dot(self)
+            }
+
+            pub fn length(&self) -> [S; $n] {
+                let mut out = self.length_squared();
+                for l in out.iter_mut() {
+                    *l = l.sqrt();
+                }
+                out
+            }
+
+            //Just like Vec3::normalized, zero length lanes end up non-finite
+            pub fn normalized(&self) -> $name<S> {
+                let mut out = *self;
+                out.normalize();
+                out
+            }
+
+            pub fn normalize(&mut self) {
+                let length_squared = self.length_squared();
+                for i in 0..$n {
+                    let scale = S::one() / length_squared[i].sqrt();
+                    self.x[i] = self.x[i] * scale;
+                    self.y[i] = self.y[i] * scale;
+                    self.z[i] = self.z[i] * scale;
+                }
+            }
+
+            //Mat4 * (p, 1) for every lane, the bottom row of the matrix is ignored, so there is no perspective divide
+            pub fn transform_points(&self, m: &Mat4<S>) -> $name<S> {
+                let mut out = $name::zero();
+                for i in 0..$n {
+                    out.x[i] = m.r0.x * self.x[i] + m.r0.y * self.y[i] + m.r0.z * self.z[i] + m.r0.w;
+                    out.y[i] = m.r1.x * self.x[i] + m.r1.y * self.y[i] + m.r1.z * self.z[i] + m.r1.w;
+                    out.z[i] = m.r2.x * self.x[i] + m.r2.y * self.y[i] + m.r2.z * self.z[i] + m.r2.w;
+                }
+                out
+            }
+
+            //Mat4 * (v, 0) for every lane, directions are not affected by the translation
+            pub fn transform_vectors(&self, m: &Mat4<S>) -> $name<S> {
+                self.transform_by_mat3(&Mat3::from(*m))
+            }
+
+            pub fn transform_by_mat3(&self, m: &Mat3<S>) -> $name<S> {
+                let mut out = $name::zero();
+                for i in 0..$n {
+                    out.x[i] = m.r0.x * self.x[i] + m.r0.y * self.y[i] + m.r0.z * self.z[i];
+                    out.y[i] = m.r1.x * self.x[i] + m.r1.y * self.y[i] + m.r1.z * self.z[i];
+                    out.z[i] = m.r2.x * self.x[i] + m.r2.y * self.y[i] + m.r2.z * self.z[i];
+                }
+                out
+            }
+        }
+
+        impl<S> Index<usize> for $name<S> where S: FloatType<S> {
+            type Output = [S; $n];
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.x,
+                    1 => &self.y,
+                    2 => &self.z,
+                    _ => panic!("Requested an invalid component of a {}: {}", stringify!($name), index)
+                }
+            }
+        }
+
+        impl<S> Add<$name<S>> for $name<S> where S: FloatType<S> {
+            type Output = $name<S>;
+
+            fn add(self, rhs: $name<S>) -> Self::Output {
+                let mut out = self;
+                for i in 0..$n {
+                    out.x[i] = out.x[i] + rhs.x[i];
+                    out.y[i] = out.y[i] + rhs.y[i];
+                    out.z[i] = out.z[i] + rhs.z[i];
+                }
+                out
+            }
+        }
+
+        impl<S> Sub<$name<S>> for $name<S> where S: FloatType<S> {
+            type Output = $name<S>;
+
+            fn sub(self, rhs: $name<S>) -> Self::Output {
+                let mut out = self;
+                for i in 0..$n {
+                    out.x[i] = out.x[i] - rhs.x[i];
+                    out.y[i] = out.y[i] - rhs.y[i];
+                    out.z[i] = out.z[i] - rhs.z[i];
+                }
+                out
+            }
+        }
+
+        impl<S> Neg for $name<S> where S: FloatType<S> {
+            type Output = $name<S>;
+
+            fn neg(self) -> Self::Output {
+                self * -S::one()
+            }
+        }
+
+        //Component-wise, like Vec3 * Vec3
+        impl<S> Mul<$name<S>> for $name<S> where S: FloatType<S> {
+            type Output = $name<S>;
+
+            fn mul(self, rhs: $name<S>) -> Self::Output {
+                let mut out = self;
+                for i in 0..$n {
+                    out.x[i] = out.x[i] * rhs.x[i];
+                    out.y[i] = out.y[i] * rhs.y[i];
+                    out.z[i] = out.z[i] * rhs.z[i];
+                }
+                out
+            }
+        }
+
+        impl<S> Mul<S> for $name<S> where S: FloatType<S> {
+            type Output = $name<S>;
+
+            fn mul(self, rhs: S) -> Self::Output {
+                let mut out = self;
+                for i in 0..$n {
+                    out.x[i] = out.x[i] * rhs;
+                    out.y[i] = out.y[i] * rhs;
+                    out.z[i] = out.z[i] * rhs;
+                }
+                out
+            }
+        }
+
+        impl<S> From<[Vec3<S>; $n]> for $name<S> where S: FloatType<S> {
+            fn from(vectors: [Vec3<S>; $n]) -> $name<S> {
+                $name::load(&vectors)
+            }
+        }
+
+        impl<S> From<$name<S>> for [Vec3<S>; $n] where S: FloatType<S> {
+            fn from(lanes: $name<S>) -> [Vec3<S>; $n] {
+                let mut out = [Vec3::zero(); $n];
+                lanes.store(&mut out);
+                out
+            }
+        }
+
+        impl<S> PartialEq for $name<S> where S: FloatType<S> {
+            fn eq(&self, other: &$name<S>) -> bool {
+                (0..$n).all(|i| self.get(i) == other.get(i))
+            }
+        }
+
+        impl<S> Default for $name<S> where S: FloatType<S> {
+            fn default() -> $name<S> {
+                $name::zero()
+            }
+        }
+    };
+}
+
+vec3_lanes!(Vec3x4, 4);
+vec3_lanes!(Vec3x8, 8);
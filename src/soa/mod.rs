@@ -0,0 +1,25 @@
+//Structure-of-arrays versions of Vec3 for bulk math, every component is stored in its own array,
+//so the same operation runs on 4 or 8 vectors at once and the compiler can turn the loops over the lanes into SIMD,
+//Vec3x4 and Vec3x8 are fixed size lane groups, Vec3Soa is a growable container of any length (needs std),
+//all of them convert to and from slices of Vec3 and Vec4, so they can be dropped into AoS code
+
+use super::*;
+
+pub use self::lanes::*;
+#[cfg(feature = "std")]
+pub use self::vec3_soa::*;
+
+pub mod lanes;
+#[cfg(feature = "std")]
+pub mod vec3_soa;
+
+pub type Vec3x4n = Vec3x4<f32>;
+pub type Vec3x4h = Vec3x4<f64>;
+
+pub type Vec3x8n = Vec3x8<f32>;
+pub type Vec3x8h = Vec3x8<f64>;
+
+#[cfg(feature = "std")]
+pub type Vec3Soan = Vec3Soa<f32>;
+#[cfg(feature = "std")]
+pub type Vec3Soah = Vec3Soa<f64>;
@@ -0,0 +1,187 @@
+use super::*;
+
+//Any number of Vec3s as 3 separate arrays, the batch operations work on all of them at once and write into
+//caller provided outputs where they can, so a frame's worth of points does not need new allocations every frame,
+//the 3 arrays always have the same length, so they are only handed out as slices
+#[derive(Clone, Debug, Default)]
+pub struct Vec3Soa<S> {
+    x: Vec<S>,
+    y: Vec<S>,
+    z: Vec<S>,
+}
+
+impl<S> Vec3Soa<S> where S: FloatType<S> {
+    pub fn new() -> Vec3Soa<S> {
+        Vec3Soa {
+            x: Vec::new(),
+            y: Vec::new(),
+            z: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Vec3Soa<S> {
+        Vec3Soa {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            z: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn from_slice(vectors: &[Vec3<S>]) -> Vec3Soa<S> {
+        let mut soa = Vec3Soa::with_capacity(vectors.len());
+        for v in vectors {
+            soa.push(*v);
+        }
+        soa
+    }
+
+    //The xyz of every vector, w is dropped
+    pub fn from_vec4_slice(vectors: &[Vec4<S>]) -> Vec3Soa<S> {
+        let mut soa = Vec3Soa::with_capacity(vectors.len());
+        for v in vectors {
+            soa.push(Vec3 { x: v.x, y: v.y, z: v.z });
+        }
+        soa
+    }
+
+    pub fn to_vec(&self) -> Vec<Vec3<S>> {
+        (0..self.len()).map(|i| self.get(i)).collect()
+    }
+
+    //Writes as many vectors as fit into the slice
+    pub fn store(&self, vectors: &mut [Vec3<S>]) {
+        for (i, v) in vectors.iter_mut().take(self.len()).enumerate() {
+            *v = self.get(i);
+        }
+    }
+
+    //Writes into xyz, w is left alone
+    pub fn store_vec4(&self, vectors: &mut [Vec4<S>]) {
+        for (i, v) in vectors.iter_mut().take(self.len()).enumerate() {
+            v.x = self.x[i];
+            v.y = self.y[i];
+            v.z = self.z[i];
+        }
+    }
+
+    //The x, y and z arrays
+    pub fn as_slices(&self) -> (&[S], &[S], &[S]) {
+        (&self.x, &self.y, &self.z)
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [S], &mut [S], &mut [S]) {
+        (&mut self.x, &mut self.y, &mut self.z)
+    }
+
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    pub fn push(&mut self, v: Vec3<S>) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+        self.z.push(v.z);
+    }
+
+    pub fn clear(&mut self) {
+        self.x.clear();
+        self.y.clear();
+        self.z.clear();
+    }
+
+    pub fn get(&self, index: usize) -> Vec3<S> {
+        Vec3 { x: self.x[index], y: self.y[index], z: self.z[index] }
+    }
+
+    pub fn set(&mut self, index: usize, v: Vec3<S>) {
+        self.x[index] = v.x;
+        self.y[index] = v.y;
+        self.z[index] = v.z;
+    }
+
+    //Both need to be the same length (panics otherwise), out gets resized to it
+    pub fn dot_into(&self, rhs: &Vec3Soa<S>, out: &mut Vec<S>) {
+        assert_eq!(self.len(), rhs.len());
+        out.clear();
+        out.extend(self.x.iter().zip(&self.y).zip(&self.z).zip(rhs.x.iter().zip(&rhs.y).zip(&rhs.z))
+            .map(|(((&x0, &y0), &z0), ((&x1, &y1), &z1))| x0 * x1 + y0 * y1 + z0 * z1));
+    }
+
+    pub fn dot(&self, rhs: &Vec3Soa<S>) -> Vec<S> {
+        let mut out = Vec::with_capacity(self.len());
+        self.dot_into(rhs, &mut out);
+        out
+    }
+
+    //Both need to be the same length, just like dot_into
+    pub fn cross(&self, rhs: &Vec3Soa<S>) -> Vec3Soa<S> {
+        assert_eq!(self.len(), rhs.len());
+        let mut out = Vec3Soa::with_capacity(self.len());
+        for i in 0..self.len() {
+            out.push(self.get(i).cross(rhs.get(i)));
+        }
+        out
+    }
+
+    //In place, just like Vec3::normalize, zero length vectors end up non-finite
+    pub fn normalize(&mut self) {
+        for ((x, y), z) in self.x.iter_mut().zip(self.y.iter_mut()).zip(self.z.iter_mut()) {
+            let scale = S::one() / (*x * *x + *y * *y + *z * *z).sqrt();
+            *x = *x * scale;
+            *y = *y * scale;
+            *z = *z * scale;
+        }
+    }
+
+    //Mat4 * (p, 1) for every point, in place, the bottom row of the matrix is ignored, so there is no perspective divide
+    pub fn transform_points(&mut self, m: &Mat4<S>) {
+        for ((x, y), z) in self.x.iter_mut().zip(self.y.iter_mut()).zip(self.z.iter_mut()) {
+            let (px, py, pz) = (*x, *y, *z);
+            *x = m.r0.x * px + m.r0.y * py + m.r0.z * pz + m.r0.w;
+            *y = m.r1.x * px + m.r1.y * py + m.r1.z * pz + m.r1.w;
+            *z = m.r2.x * px + m.r2.y * py + m.r2.z * pz + m.r2.w;
+        }
+    }
+
+    //Mat4 * (v, 0) for every direction, in place
+    pub fn transform_vectors(&mut self, m: &Mat4<S>) {
+        self.transform_by_mat3(&Mat3::from(*m));
+    }
+
+    pub fn transform_by_mat3(&mut self, m: &Mat3<S>) {
+        for ((x, y), z) in self.x.iter_mut().zip(self.y.iter_mut()).zip(self.z.iter_mut()) {
+            let (vx, vy, vz) = (*x, *y, *z);
+            *x = m.r0.x * vx + m.r0.y * vy + m.r0.z * vz;
+            *y = m.r1.x * vx + m.r1.y * vy + m.r1.z * vz;
+            *z = m.r2.x * vx + m.r2.y * vy + m.r2.z * vz;
+        }
+    }
+}
+
+impl<S> From<&[Vec3<S>]> for Vec3Soa<S> where S: FloatType<S> {
+    fn from(vectors: &[Vec3<S>]) -> Vec3Soa<S> {
+        Vec3Soa::from_slice(vectors)
+    }
+}
+
+impl<S> From<&[Vec4<S>]> for Vec3Soa<S> where S: FloatType<S> {
+    fn from(vectors: &[Vec4<S>]) -> Vec3Soa<S> {
+        Vec3Soa::from_vec4_slice(vectors)
+    }
+}
+
+impl<S> From<Vec3Soa<S>> for Vec<Vec3<S>> where S: FloatType<S> {
+    fn from(soa: Vec3Soa<S>) -> Vec<Vec3<S>> {
+        soa.to_vec()
+    }
+}
+
+impl<S> PartialEq for Vec3Soa<S> where S: FloatType<S> {
+    fn eq(&self, other: &Vec3Soa<S>) -> bool {
+        self.len() == other.len() && (0..self.len()).all(|i| self.get(i) == other.get(i))
+    }
+}
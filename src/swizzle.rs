@@ -0,0 +1,657 @@
+//Swizzle accessors for all vector types, like in GLSL every combination of 2 to 4 components can be read
+//(v.zyx(), v.xxyy(), v.rgba(), etc.) and every combination without repeats can be written (v.set_xz(..))
+
+use super::*;
+
+macro_rules! swizzle_get {
+    ($name:ident, $V2:ident, $V3:ident, $V4:ident, $a:ident, $b:ident) => {
+        pub fn $name(&self) -> $V2<S> {
+            $V2 { x: self.$a, y: self.$b }
+        }
+    };
+    ($name:ident, $V2:ident, $V3:ident, $V4:ident, $a:ident, $b:ident, $c:ident) => {
+        pub fn $name(&self) -> $V3<S> {
+            $V3 { x: self.$a, y: self.$b, z: self.$c }
+        }
+    };
+    ($name:ident, $V2:ident, $V3:ident, $V4:ident, $a:ident, $b:ident, $c:ident, $d:ident) => {
+        pub fn $name(&self) -> $V4<S> {
+            $V4 { x: self.$a, y: self.$b, z: self.$c, w: self.$d }
+        }
+    };
+}
+
+macro_rules! swizzle_set {
+    ($name:ident, $V2:ident, $V3:ident, $V4:ident, $a:ident, $b:ident) => {
+        pub fn $name(&mut self, v: $V2<S>) {
+            self.$a = v.x;
+            self.$b = v.y;
+        }
+    };
+    ($name:ident, $V2:ident, $V3:ident, $V4:ident, $a:ident, $b:ident, $c:ident) => {
+        pub fn $name(&mut self, v: $V3<S>) {
+            self.$a = v.x;
+            self.$b = v.y;
+            self.$c = v.z;
+        }
+    };
+    ($name:ident, $V2:ident, $V3:ident, $V4:ident, $a:ident, $b:ident, $c:ident, $d:ident) => {
+        pub fn $name(&mut self, v: $V4<S>) {
+            self.$a = v.x;
+            self.$b = v.y;
+            self.$c = v.z;
+            self.$d = v.w;
+        }
+    };
+}
+
+//Every entry is the xyzw name, the rgba name and the components it reads or writes
+macro_rules! impl_swizzles {
+    ($T:ident, $bound:path, $V2:ident, $V3:ident, $V4:ident,
+     get { $($get:ident $get_rgba:ident [$($gc:ident)+])* }
+     set { $($set:ident $set_rgba:ident [$($sc:ident)+])* }) => {
+        impl<S> $T<S> where S: $bound {
+            $(
+                swizzle_get!($get, $V2, $V3, $V4, $($gc),+);
+                swizzle_get!($get_rgba, $V2, $V3, $V4, $($gc),+);
+            )*
+            $(
+                swizzle_set!($set, $V2, $V3, $V4, $($sc),+);
+                swizzle_set!($set_rgba, $V2, $V3, $V4, $($sc),+);
+            )*
+        }
+    };
+}
+
+macro_rules! vec2_swizzles {
+    ($T:ident, $bound:path, $V2:ident, $V3:ident, $V4:ident) => {
+        impl_swizzles!($T, $bound, $V2, $V3, $V4,
+            get {
+                xx rr [x x]
+                xy rg [x y]
+                yx gr [y x]
+                yy gg [y y]
+                xxx rrr [x x x]
+                xxy rrg [x x y]
+                xyx rgr [x y x]
+                xyy rgg [x y y]
+                yxx grr [y x x]
+                yxy grg [y x y]
+                yyx ggr [y y x]
+                yyy ggg [y y y]
+                xxxx rrrr [x x x x]
+                xxxy rrrg [x x x y]
+                xxyx rrgr [x x y x]
+                xxyy rrgg [x x y y]
+                xyxx rgrr [x y x x]
+                xyxy rgrg [x y x y]
+                xyyx rggr [x y y x]
+                xyyy rggg [x y y y]
+                yxxx grrr [y x x x]
+                yxxy grrg [y x x y]
+                yxyx grgr [y x y x]
+                yxyy grgg [y x y y]
+                yyxx ggrr [y y x x]
+                yyxy ggrg [y y x y]
+                yyyx gggr [y y y x]
+                yyyy gggg [y y y y]
+            }
+            set {
+                set_xy set_rg [x y]
+                set_yx set_gr [y x]
+            });
+    };
+}
+
+macro_rules! vec3_swizzles {
+    ($T:ident, $bound:path, $V2:ident, $V3:ident, $V4:ident) => {
+        impl_swizzles!($T, $bound, $V2, $V3, $V4,
+            get {
+                xx rr [x x]
+                xy rg [x y]
+                xz rb [x z]
+                yx gr [y x]
+                yy gg [y y]
+                yz gb [y z]
+                zx br [z x]
+                zy bg [z y]
+                zz bb [z z]
+                xxx rrr [x x x]
+                xxy rrg [x x y]
+                xxz rrb [x x z]
+                xyx rgr [x y x]
+                xyy rgg [x y y]
+                xyz rgb [x y z]
+                xzx rbr [x z x]
+                xzy rbg [x z y]
+                xzz rbb [x z z]
+                yxx grr [y x x]
+                yxy grg [y x y]
+                yxz grb [y x z]
+                yyx ggr [y y x]
+                yyy ggg [y y y]
+                yyz ggb [y y z]
+                yzx gbr [y z x]
+                yzy gbg [y z y]
+                yzz gbb [y z z]
+                zxx brr [z x x]
+                zxy brg [z x y]
+                zxz brb [z x z]
+                zyx bgr [z y x]
+                zyy bgg [z y y]
+                zyz bgb [z y z]
+                zzx bbr [z z x]
+                zzy bbg [z z y]
+                zzz bbb [z z z]
+                xxxx rrrr [x x x x]
+                xxxy rrrg [x x x y]
+                xxxz rrrb [x x x z]
+                xxyx rrgr [x x y x]
+                xxyy rrgg [x x y y]
+                xxyz rrgb [x x y z]
+                xxzx rrbr [x x z x]
+                xxzy rrbg [x x z y]
+                xxzz rrbb [x x z z]
+                xyxx rgrr [x y x x]
+                xyxy rgrg [x y x y]
+                xyxz rgrb [x y x z]
+                xyyx rggr [x y y x]
+                xyyy rggg [x y y y]
+                xyyz rggb [x y y z]
+                xyzx rgbr [x y z x]
+                xyzy rgbg [x y z y]
+                xyzz rgbb [x y z z]
+                xzxx rbrr [x z x x]
+                xzxy rbrg [x z x y]
+                xzxz rbrb [x z x z]
+                xzyx rbgr [x z y x]
+                xzyy rbgg [x z y y]
+                xzyz rbgb [x z y z]
+                xzzx rbbr [x z z x]
+                xzzy rbbg [x z z y]
+                xzzz rbbb [x z z z]
+                yxxx grrr [y x x x]
+                yxxy grrg [y x x y]
+                yxxz grrb [y x x z]
+                yxyx grgr [y x y x]
+                yxyy grgg [y x y y]
+                yxyz grgb [y x y z]
+                yxzx grbr [y x z x]
+                yxzy grbg [y x z y]
+                yxzz grbb [y x z z]
+                yyxx ggrr [y y x x]
+                yyxy ggrg [y y x y]
+                yyxz ggrb [y y x z]
+                yyyx gggr [y y y x]
+                yyyy gggg [y y y y]
+                yyyz gggb [y y y z]
+                yyzx ggbr [y y z x]
+                yyzy ggbg [y y z y]
+                yyzz ggbb [y y z z]
+                yzxx gbrr [y z x x]
+                yzxy gbrg [y z x y]
+                yzxz gbrb [y z x z]
+                yzyx gbgr [y z y x]
+                yzyy gbgg [y z y y]
+                yzyz gbgb [y z y z]
+                yzzx gbbr [y z z x]
+                yzzy gbbg [y z z y]
+                yzzz gbbb [y z z z]
+                zxxx brrr [z x x x]
+                zxxy brrg [z x x y]
+                zxxz brrb [z x x z]
+                zxyx brgr [z x y x]
+                zxyy brgg [z x y y]
+                zxyz brgb [z x y z]
+                zxzx brbr [z x z x]
+                zxzy brbg [z x z y]
+                zxzz brbb [z x z z]
+                zyxx bgrr [z y x x]
+                zyxy bgrg [z y x y]
+                zyxz bgrb [z y x z]
+                zyyx bggr [z y y x]
+                zyyy bggg [z y y y]
+                zyyz bggb [z y y z]
+                zyzx bgbr [z y z x]
+                zyzy bgbg [z y z y]
+                zyzz bgbb [z y z z]
+                zzxx bbrr [z z x x]
+                zzxy bbrg [z z x y]
+                zzxz bbrb [z z x z]
+                zzyx bbgr [z z y x]
+                zzyy bbgg [z z y y]
+                zzyz bbgb [z z y z]
+                zzzx bbbr [z z z x]
+                zzzy bbbg [z z z y]
+                zzzz bbbb [z z z z]
+            }
+            set {
+                set_xy set_rg [x y]
+                set_xz set_rb [x z]
+                set_yx set_gr [y x]
+                set_yz set_gb [y z]
+                set_zx set_br [z x]
+                set_zy set_bg [z y]
+                set_xyz set_rgb [x y z]
+                set_xzy set_rbg [x z y]
+                set_yxz set_grb [y x z]
+                set_yzx set_gbr [y z x]
+                set_zxy set_brg [z x y]
+                set_zyx set_bgr [z y x]
+            });
+    };
+}
+
+macro_rules! vec4_swizzles {
+    ($T:ident, $bound:path, $V2:ident, $V3:ident, $V4:ident) => {
+        impl_swizzles!($T, $bound, $V2, $V3, $V4,
+            get {
+                xx rr [x x]
+                xy rg [x y]
+                xz rb [x z]
+                xw ra [x w]
+                yx gr [y x]
+                yy gg [y y]
+                yz gb [y z]
+                yw ga [y w]
+                zx br [z x]
+                zy bg [z y]
+                zz bb [z z]
+                zw ba [z w]
+                wx ar [w x]
+                wy ag [w y]
+                wz ab [w z]
+                ww aa [w w]
+                xxx rrr [x x x]
+                xxy rrg [x x y]
+                xxz rrb [x x z]
+                xxw rra [x x w]
+                xyx rgr [x y x]
+                xyy rgg [x y y]
+                xyz rgb [x y z]
+                xyw rga [x y w]
+                xzx rbr [x z x]
+                xzy rbg [x z y]
+                xzz rbb [x z z]
+                xzw rba [x z w]
+                xwx rar [x w x]
+                xwy rag [x w y]
+                xwz rab [x w z]
+                xww raa [x w w]
+                yxx grr [y x x]
+                yxy grg [y x y]
+                yxz grb [y x z]
+                yxw gra [y x w]
+                yyx ggr [y y x]
+                yyy ggg [y y y]
+                yyz ggb [y y z]
+                yyw gga [y y w]
+                yzx gbr [y z x]
+                yzy gbg [y z y]
+                yzz gbb [y z z]
+                yzw gba [y z w]
+                ywx gar [y w x]
+                ywy gag [y w y]
+                ywz gab [y w z]
+                yww gaa [y w w]
+                zxx brr [z x x]
+                zxy brg [z x y]
+                zxz brb [z x z]
+                zxw bra [z x w]
+                zyx bgr [z y x]
+                zyy bgg [z y y]
+                zyz bgb [z y z]
+                zyw bga [z y w]
+                zzx bbr [z z x]
+                zzy bbg [z z y]
+                zzz bbb [z z z]
+                zzw bba [z z w]
+                zwx bar [z w x]
+                zwy bag [z w y]
+                zwz bab [z w z]
+                zww baa [z w w]
+                wxx arr [w x x]
+                wxy arg [w x y]
+                wxz arb [w x z]
+                wxw ara [w x w]
+                wyx agr [w y x]
+                wyy agg [w y y]
+                wyz agb [w y z]
+                wyw aga [w y w]
+                wzx abr [w z x]
+                wzy abg [w z y]
+                wzz abb [w z z]
+                wzw aba [w z w]
+                wwx aar [w w x]
+                wwy aag [w w y]
+                wwz aab [w w z]
+                www aaa [w w w]
+                xxxx rrrr [x x x x]
+                xxxy rrrg [x x x y]
+                xxxz rrrb [x x x z]
+                xxxw rrra [x x x w]
+                xxyx rrgr [x x y x]
+                xxyy rrgg [x x y y]
+                xxyz rrgb [x x y z]
+                xxyw rrga [x x y w]
+                xxzx rrbr [x x z x]
+                xxzy rrbg [x x z y]
+                xxzz rrbb [x x z z]
+                xxzw rrba [x x z w]
+                xxwx rrar [x x w x]
+                xxwy rrag [x x w y]
+                xxwz rrab [x x w z]
+                xxww rraa [x x w w]
+                xyxx rgrr [x y x x]
+                xyxy rgrg [x y x y]
+                xyxz rgrb [x y x z]
+                xyxw rgra [x y x w]
+                xyyx rggr [x y y x]
+                xyyy rggg [x y y y]
+                xyyz rggb [x y y z]
+                xyyw rgga [x y y w]
+                xyzx rgbr [x y z x]
+                xyzy rgbg [x y z y]
+                xyzz rgbb [x y z z]
+                xyzw rgba [x y z w]
+                xywx rgar [x y w x]
+                xywy rgag [x y w y]
+                xywz rgab [x y w z]
+                xyww rgaa [x y w w]
+                xzxx rbrr [x z x x]
+                xzxy rbrg [x z x y]
+                xzxz rbrb [x z x z]
+                xzxw rbra [x z x w]
+                xzyx rbgr [x z y x]
+                xzyy rbgg [x z y y]
+                xzyz rbgb [x z y z]
+                xzyw rbga [x z y w]
+                xzzx rbbr [x z z x]
+                xzzy rbbg [x z z y]
+                xzzz rbbb [x z z z]
+                xzzw rbba [x z z w]
+                xzwx rbar [x z w x]
+                xzwy rbag [x z w y]
+                xzwz rbab [x z w z]
+                xzww rbaa [x z w w]
+                xwxx rarr [x w x x]
+                xwxy rarg [x w x y]
+                xwxz rarb [x w x z]
+                xwxw rara [x w x w]
+                xwyx ragr [x w y x]
+                xwyy ragg [x w y y]
+                xwyz ragb [x w y z]
+                xwyw raga [x w y w]
+                xwzx rabr [x w z x]
+                xwzy rabg [x w z y]
+                xwzz rabb [x w z z]
+                xwzw raba [x w z w]
+                xwwx raar [x w w x]
+                xwwy raag [x w w y]
+                xwwz raab [x w w z]
+                xwww raaa [x w w w]
+                yxxx grrr [y x x x]
+                yxxy grrg [y x x y]
+                yxxz grrb [y x x z]
+                yxxw grra [y x x w]
+                yxyx grgr [y x y x]
+                yxyy grgg [y x y y]
+                yxyz grgb [y x y z]
+                yxyw grga [y x y w]
+                yxzx grbr [y x z x]
+                yxzy grbg [y x z y]
+                yxzz grbb [y x z z]
+                yxzw grba [y x z w]
+                yxwx grar [y x w x]
+                yxwy grag [y x w y]
+                yxwz grab [y x w z]
+                yxww graa [y x w w]
+                yyxx ggrr [y y x x]
+                yyxy ggrg [y y x y]
+                yyxz ggrb [y y x z]
+                yyxw ggra [y y x w]
+                yyyx gggr [y y y x]
+                yyyy gggg [y y y y]
+                yyyz gggb [y y y z]
+                yyyw ggga [y y y w]
+                yyzx ggbr [y y z x]
+                yyzy ggbg [y y z y]
+                yyzz ggbb [y y z z]
+                yyzw ggba [y y z w]
+                yywx ggar [y y w x]
+                yywy ggag [y y w y]
+                yywz ggab [y y w z]
+                yyww ggaa [y y w w]
+                yzxx gbrr [y z x x]
+                yzxy gbrg [y z x y]
+                yzxz gbrb [y z x z]
+                yzxw gbra [y z x w]
+                yzyx gbgr [y z y x]
+                yzyy gbgg [y z y y]
+                yzyz gbgb [y z y z]
+                yzyw gbga [y z y w]
+                yzzx gbbr [y z z x]
+                yzzy gbbg [y z z y]
+                yzzz gbbb [y z z z]
+                yzzw gbba [y z z w]
+                yzwx gbar [y z w x]
+                yzwy gbag [y z w y]
+                yzwz gbab [y z w z]
+                yzww gbaa [y z w w]
+                ywxx garr [y w x x]
+                ywxy garg [y w x y]
+                ywxz garb [y w x z]
+                ywxw gara [y w x w]
+                ywyx gagr [y w y x]
+                ywyy gagg [y w y y]
+                ywyz gagb [y w y z]
+                ywyw gaga [y w y w]
+                ywzx gabr [y w z x]
+                ywzy gabg [y w z y]
+                ywzz gabb [y w z z]
+                ywzw gaba [y w z w]
+                ywwx gaar [y w w x]
+                ywwy gaag [y w w y]
+                ywwz gaab [y w w z]
+                ywww gaaa [y w w w]
+                zxxx brrr [z x x x]
+                zxxy brrg [z x x y]
+                zxxz brrb [z x x z]
+                zxxw brra [z x x w]
+                zxyx brgr [z x y x]
+                zxyy brgg [z x y y]
+                zxyz brgb [z x y z]
+                zxyw brga [z x y w]
+                zxzx brbr [z x z x]
+                zxzy brbg [z x z y]
+                zxzz brbb [z x z z]
+                zxzw brba [z x z w]
+                zxwx brar [z x w x]
+                zxwy brag [z x w y]
+                zxwz brab [z x w z]
+                zxww braa [z x w w]
+                zyxx bgrr [z y x x]
+                zyxy bgrg [z y x y]
+                zyxz bgrb [z y x z]
+                zyxw bgra [z y x w]
+                zyyx bggr [z y y x]
+                zyyy bggg [z y y y]
+                zyyz bggb [z y y z]
+                zyyw bgga [z y y w]
+                zyzx bgbr [z y z x]
+                zyzy bgbg [z y z y]
+                zyzz bgbb [z y z z]
+                zyzw bgba [z y z w]
+                zywx bgar [z y w x]
+                zywy bgag [z y w y]
+                zywz bgab [z y w z]
+                zyww bgaa [z y w w]
+                zzxx bbrr [z z x x]
+                zzxy bbrg [z z x y]
+                zzxz bbrb [z z x z]
+                zzxw bbra [z z x w]
+                zzyx bbgr [z z y x]
+                zzyy bbgg [z z y y]
+                zzyz bbgb [z z y z]
+                zzyw bbga [z z y w]
+                zzzx bbbr [z z z x]
+                zzzy bbbg [z z z y]
+                zzzz bbbb [z z z z]
+                zzzw bbba [z z z w]
+                zzwx bbar [z z w x]
+                zzwy bbag [z z w y]
+                zzwz bbab [z z w z]
+                zzww bbaa [z z w w]
+                zwxx barr [z w x x]
+                zwxy barg [z w x y]
+                zwxz barb [z w x z]
+                zwxw bara [z w x w]
+                zwyx bagr [z w y x]
+                zwyy bagg [z w y y]
+                zwyz bagb [z w y z]
+                zwyw baga [z w y w]
+                zwzx babr [z w z x]
+                zwzy babg [z w z y]
+                zwzz babb [z w z z]
+                zwzw baba [z w z w]
+                zwwx baar [z w w x]
+                zwwy baag [z w w y]
+                zwwz baab [z w w z]
+                zwww baaa [z w w w]
+                wxxx arrr [w x x x]
+                wxxy arrg [w x x y]
+                wxxz arrb [w x x z]
+                wxxw arra [w x x w]
+                wxyx argr [w x y x]
+                wxyy argg [w x y y]
+                wxyz argb [w x y z]
+                wxyw arga [w x y w]
+                wxzx arbr [w x z x]
+                wxzy arbg [w x z y]
+                wxzz arbb [w x z z]
+                wxzw arba [w x z w]
+                wxwx arar [w x w x]
+                wxwy arag [w x w y]
+                wxwz arab [w x w z]
+                wxww araa [w x w w]
+                wyxx agrr [w y x x]
+                wyxy agrg [w y x y]
+                wyxz agrb [w y x z]
+                wyxw agra [w y x w]
+                wyyx aggr [w y y x]
+                wyyy aggg [w y y y]
+                wyyz aggb [w y y z]
+                wyyw agga [w y y w]
+                wyzx agbr [w y z x]
+                wyzy agbg [w y z y]
+                wyzz agbb [w y z z]
+                wyzw agba [w y z w]
+                wywx agar [w y w x]
+                wywy agag [w y w y]
+                wywz agab [w y w z]
+                wyww agaa [w y w w]
+                wzxx abrr [w z x x]
+                wzxy abrg [w z x y]
+                wzxz abrb [w z x z]
+                wzxw abra [w z x w]
+                wzyx abgr [w z y x]
+                wzyy abgg [w z y y]
+                wzyz abgb [w z y z]
+                wzyw abga [w z y w]
+                wzzx abbr [w z z x]
+                wzzy abbg [w z z y]
+                wzzz abbb [w z z z]
+                wzzw abba [w z z w]
+                wzwx abar [w z w x]
+                wzwy abag [w z w y]
+                wzwz abab [w z w z]
+                wzww abaa [w z w w]
+                wwxx aarr [w w x x]
+                wwxy aarg [w w x y]
+                wwxz aarb [w w x z]
+                wwxw aara [w w x w]
+                wwyx aagr [w w y x]
+                wwyy aagg [w w y y]
+                wwyz aagb [w w y z]
+                wwyw aaga [w w y w]
+                wwzx aabr [w w z x]
+                wwzy aabg [w w z y]
+                wwzz aabb [w w z z]
+                wwzw aaba [w w z w]
+                wwwx aaar [w w w x]
+                wwwy aaag [w w w y]
+                wwwz aaab [w w w z]
+                wwww aaaa [w w w w]
+            }
+            set {
+                set_xy set_rg [x y]
+                set_xz set_rb [x z]
+                set_xw set_ra [x w]
+                set_yx set_gr [y x]
+                set_yz set_gb [y z]
+                set_yw set_ga [y w]
+                set_zx set_br [z x]
+                set_zy set_bg [z y]
+                set_zw set_ba [z w]
+                set_wx set_ar [w x]
+                set_wy set_ag [w y]
+                set_wz set_ab [w z]
+                set_xyz set_rgb [x y z]
+                set_xyw set_rga [x y w]
+                set_xzy set_rbg [x z y]
+                set_xzw set_rba [x z w]
+                set_xwy set_rag [x w y]
+                set_xwz set_rab [x w z]
+                set_yxz set_grb [y x z]
+                set_yxw set_gra [y x w]
+                set_yzx set_gbr [y z x]
+                set_yzw set_gba [y z w]
+                set_ywx set_gar [y w x]
+                set_ywz set_gab [y w z]
+                set_zxy set_brg [z x y]
+                set_zxw set_bra [z x w]
+                set_zyx set_bgr [z y x]
+                set_zyw set_bga [z y w]
+                set_zwx set_bar [z w x]
+                set_zwy set_bag [z w y]
+                set_wxy set_arg [w x y]
+                set_wxz set_arb [w x z]
+                set_wyx set_agr [w y x]
+                set_wyz set_agb [w y z]
+                set_wzx set_abr [w z x]
+                set_wzy set_abg [w z y]
+                set_xyzw set_rgba [x y z w]
+                set_xywz set_rgab [x y w z]
+                set_xzyw set_rbga [x z y w]
+                set_xzwy set_rbag [x z w y]
+                set_xwyz set_ragb [x w y z]
+                set_xwzy set_rabg [x w z y]
+                set_yxzw set_grba [y x z w]
+                set_yxwz set_grab [y x w z]
+                set_yzxw set_gbra [y z x w]
+                set_yzwx set_gbar [y z w x]
+                set_ywxz set_garb [y w x z]
+                set_ywzx set_gabr [y w z x]
+                set_zxyw set_brga [z x y w]
+                set_zxwy set_brag [z x w y]
+                set_zyxw set_bgra [z y x w]
+                set_zywx set_bgar [z y w x]
+                set_zwxy set_barg [z w x y]
+                set_zwyx set_bagr [z w y x]
+                set_wxyz set_argb [w x y z]
+                set_wxzy set_arbg [w x z y]
+                set_wyxz set_agrb [w y x z]
+                set_wyzx set_agbr [w y z x]
+                set_wzxy set_abrg [w z x y]
+                set_wzyx set_abgr [w z y x]
+            });
+    };
+}
+
+vec2_swizzles!(Vec2, FloatType<S>, Vec2, Vec3, Vec4);
+vec3_swizzles!(Vec3, FloatType<S>, Vec2, Vec3, Vec4);
+vec4_swizzles!(Vec4, FloatType<S>, Vec2, Vec3, Vec4);
+
+vec2_swizzles!(IVec2, IntegerType, IVec2, IVec3, IVec4);
+vec3_swizzles!(IVec3, IntegerType, IVec2, IVec3, IVec4);
+vec4_swizzles!(IVec4, IntegerType, IVec2, IVec3, IVec4);
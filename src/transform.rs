@@ -0,0 +1,177 @@
+use core::fmt;
+use core::ops::*;
+
+use super::*;
+
+//Translation, rotation and scale, applied to points in the order scale, rotate, translate,
+//the rotation works the same way as Quat * Vec3, so Transform::from_rotation(q).transform_point(p) == q * p
+#[derive(Copy, Clone, Debug)]
+pub struct Transform<S> {
+    pub translation: Vec3<S>,
+    pub rotation: Quat<S>,
+    pub scale: Vec3<S>,
+}
+
+impl<S> Transform<S> where S: FloatType<S> {
+    pub fn identity() -> Transform<S> {
+        Transform {
+            translation: Vec3::zero(),
+            rotation: Quat::identity(),
+            scale: Vec3::one(),
+        }
+    }
+
+    pub fn new(translation: Vec3<S>, rotation: Quat<S>, scale: Vec3<S>) -> Transform<S> {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn from_translation(translation: Vec3<S>) -> Transform<S> {
+        Transform { translation, ..Transform::identity() }
+    }
+
+    pub fn from_rotation(rotation: Quat<S>) -> Transform<S> {
+        Transform { rotation, ..Transform::identity() }
+    }
+
+    pub fn from_scale(scale: Vec3<S>) -> Transform<S> {
+        Transform { scale, ..Transform::identity() }
+    }
+
+    //Decomposes the matrix, shear can not be represented and is dropped,
+    //None for singular matrices and projections, which can not be decomposed
+    pub fn try_from_mat4(m: Mat4<S>) -> Option<Transform<S>> {
+        m.decompose().map(Transform::from)
+    }
+
+    pub fn transform_point(&self, p: Vec3<S>) -> Vec3<S> {
+        self.translation + self.rotation * (p * self.scale)
+    }
+
+    //Directions and offsets, these are not affected by the translation
+    pub fn transform_vector(&self, v: Vec3<S>) -> Vec3<S> {
+        self.rotation * (v * self.scale)
+    }
+
+    //Normals are scaled by the inverse of the scale to stay perpendicular to the surface, the result is normalized
+    pub fn transform_normal(&self, n: Vec3<S>) -> Vec3<S> {
+        (self.rotation * (n / self.scale)).normalized()
+    }
+
+    //Exact for uniform scales, a non-uniform scale followed by a rotation cannot be inverted into another
+    //scale-rotate-translate transform, the shear that this would need is dropped
+    pub fn inverse(&self) -> Transform<S> {
+        let rotation = self.rotation.normalized().conjugate();
+        let scale = Vec3::one() / self.scale;
+        Transform {
+            translation: -((rotation * self.translation) * scale),
+            rotation,
+            scale,
+        }
+    }
+
+    //Interpolates the translation and scale linearly and the rotation with Quat::lerp
+    pub fn lerp(&self, other: &Transform<S>, t: S) -> Transform<S> {
+        Transform {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: self.rotation.lerp(other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+
+    //Interpolates the translation and scale linearly and the rotation with Quat::slerp
+    pub fn slerp(&self, other: &Transform<S>, t: S) -> Transform<S> {
+        Transform {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+
+    pub fn get_mat(&self) -> Mat4<S> {
+        Mat4::from(*self)
+    }
+
+    pub fn translate(&mut self, trans: Vec3<S>) {
+        self.translation += trans;
+    }
+
+    //Rotates around the origin of the parent space, which also moves the translation
+    pub fn rotate(&mut self, rotation: Quat<S>) {
+        self.translation = rotation * self.translation;
+        self.rotation = rotation * self.rotation;
+    }
+}
+
+//Applies rhs first, then self, like Mat4 * Mat4, the result is exact as long as self has a uniform scale,
+//otherwise the shear that the combination would need is dropped
+impl<S> Mul<Transform<S>> for Transform<S> where S: FloatType<S> {
+    type Output = Transform<S>;
+
+    fn mul(self, rhs: Transform<S>) -> Self::Output {
+        Transform {
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation * rhs.rotation,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl<S> MulAssign<Transform<S>> for Transform<S> where S: FloatType<S> {
+    fn mul_assign(&mut self, rhs: Transform<S>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S> Mul<Vec3<S>> for Transform<S> where S: FloatType<S> {
+    type Output = Vec3<S>;
+
+    fn mul(self, rhs: Vec3<S>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+
+impl<S> Not for Transform<S> where S: FloatType<S> {
+    type Output = Transform<S>;
+
+    fn not(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<S> PartialEq for Transform<S> where S: FloatType<S> {
+    fn eq(&self, other: &Transform<S>) -> bool {
+        self.translation == other.translation &&
+            (self.rotation == other.rotation || self.rotation == -other.rotation) &&
+            self.scale == other.scale
+    }
+}
+
+impl<S> fmt::Display for Transform<S> where S: FloatType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "T: {} R: {} S: {}", self.translation, self.rotation, self.scale)
+    }
+}
+
+//The shear can not be represented and is dropped
+impl<S> From<Decomposition<S>> for Transform<S> where S: FloatType<S> {
+    fn from(d: Decomposition<S>) -> Transform<S> {
+        Transform::new(d.translation, d.rotation, d.scale)
+    }
+}
+
+impl<S> From<DualQuat<S>> for Transform<S> where S: FloatType<S> {
+    fn from(dq: DualQuat<S>) -> Transform<S> {
+        let dq = dq.normalized();
+        Transform::new(dq.get_translation(), dq.real, Vec3::one())
+    }
+}
+
+impl<S> Default for Transform<S> where S: FloatType<S> {
+    fn default() -> Transform<S> {
+        Transform::identity()
+    }
+}
@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -296,47 +296,60 @@ impl<S, U> From<Vec4<U>> for Vec2<S> where S: FloatType<S>, U: InputType {
     }
 }
 
+impl<S> From<Complex<S>> for Vec2<S> where S: FloatType<S> {
+    fn from(c: Complex<S>) -> Vec2<S> {
+        Vec2 {
+            x: c.re,
+            y: c.im,
+        }
+    }
+}
+
 impl<S> Default for Vec2<S> where S: FloatType<S> {
     fn default() -> Vec2<S> {
         Vec2::zero()
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Vec2<f32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Vec2(std::mem::transmute::<Vec2<f32>, [f32; 2]>(*self))
+            glium::uniforms::UniformValue::Vec2(core::mem::transmute::<Vec2<f32>, [f32; 2]>(*self))
         }
     }
 }
 
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Vec2<f64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::DoubleVec2(std::mem::transmute::<Vec2<f64>, [f64; 2]>(*self))
+            glium::uniforms::UniformValue::DoubleVec2(core::mem::transmute::<Vec2<f64>, [f64; 2]>(*self))
         }
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec2<f32> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::F32F32
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec2<f64> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::F64F64
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
\ No newline at end of file
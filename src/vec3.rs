@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -81,6 +81,15 @@ impl<S> Vec3<S> where S: FloatType<S>,
         }
     }
 
+    //The outer product, the plane going from self towards rhs, with the area of the parallelogram they span as magnitude
+    pub fn wedge(self, rhs: Vec3<S>) -> ga::Bivector3<S> {
+        ga::Bivector3 {
+            xy: self.x * rhs.y - self.y * rhs.x,
+            yz: self.y * rhs.z - self.z * rhs.y,
+            zx: self.z * rhs.x - self.x * rhs.z,
+        }
+    }
+
     pub fn length_squared(self) -> S {
         self.dot(self)
     }
@@ -359,41 +368,45 @@ impl<S> Default for Vec3<S> where S: FloatType<S> {
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Vec3<f32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Vec3(std::mem::transmute::<Vec3<f32>, [f32; 3]>(*self))
+            glium::uniforms::UniformValue::Vec3(core::mem::transmute::<Vec3<f32>, [f32; 3]>(*self))
         }
     }
 }
 
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Vec3<f64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::DoubleVec3(std::mem::transmute::<Vec3<f64>, [f64; 3]>(*self))
+            glium::uniforms::UniformValue::DoubleVec3(core::mem::transmute::<Vec3<f64>, [f64; 3]>(*self))
         }
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec3<f32> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::F32F32F32
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec3<f64> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::F64F64F64
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use super::*;
 
@@ -434,41 +434,45 @@ impl<S> Default for Vec4<S> where S: FloatType<S> {
     }
 }
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Vec4<f32> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::Vec4(std::mem::transmute::<Vec4<f32>, [f32; 4]>(*self))
+            glium::uniforms::UniformValue::Vec4(core::mem::transmute::<Vec4<f32>, [f32; 4]>(*self))
         }
     }
 }
 
 
+#[cfg(feature = "glium")]
 impl glium::uniforms::AsUniformValue for Vec4<f64> {
-    fn as_uniform_value(&self) -> glium::uniforms::UniformValue {
+    fn as_uniform_value(&self) -> glium::uniforms::UniformValue<'_> {
         unsafe {
-            glium::uniforms::UniformValue::DoubleVec4(std::mem::transmute::<Vec4<f64>, [f64; 4]>(*self))
+            glium::uniforms::UniformValue::DoubleVec4(core::mem::transmute::<Vec4<f64>, [f64; 4]>(*self))
         }
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec4<f32> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::F32F32F32F32
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
 
 
+#[cfg(feature = "glium")]
 unsafe impl glium::vertex::Attribute for Vec4<f64> {
     fn get_type() -> glium::vertex::AttributeType {
         glium::vertex::AttributeType::F64F64F64F64
     }
 
-    fn is_supported<C: ?Sized>(_caps: &C) -> bool where C: glium::CapabilitiesSource {
+    fn is_supported<C>(_caps: &C) -> bool where C: glium::CapabilitiesSource + ?Sized {
         true
     }
 }
\ No newline at end of file
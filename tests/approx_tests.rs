@@ -0,0 +1,105 @@
+#[cfg(test)]
+pub mod approx_tests {
+    use straal::*;
+
+    #[test]
+    fn scalar_variants() {
+        assert!(1.0f64.abs_diff_eq(1.0 + 1e-10, 1e-9));
+        assert!(!1.0f64.abs_diff_eq(1.0 + 1e-8, 1e-9));
+
+        //Relative comparisons scale with the magnitude, absolute ones do not
+        assert!(1e6f64.relative_eq(1e6 + 1e-4, 0.0, 1e-9));
+        assert!(!1e6f64.abs_diff_eq(1e6 + 1e-4, 1e-9));
+        assert!(!1.0f64.relative_eq(1.0 + 1e-4, 0.0, 1e-9));
+        assert!(0.0f64.relative_eq(1e-12, 1e-10, 1e-9));
+        assert!(!f64::INFINITY.relative_eq(f64::MAX, 1.0, 1.0));
+
+        let one_ulp_up = f32::from_bits(1.0f32.to_bits() + 1);
+        let three_ulps_up = f32::from_bits(1.0f32.to_bits() + 3);
+        assert!(1.0f32.ulps_eq(one_ulp_up, 0.0, 1));
+        assert!(!1.0f32.ulps_eq(three_ulps_up, 0.0, 2));
+        assert!(1.0f32.ulps_eq(three_ulps_up, 0.0, 3));
+        assert!(!1e-30f32.ulps_eq(-1e-30, 0.0, 4));
+        assert!(1e-30f32.ulps_eq(-1e-30, 1e-20, 4));
+        assert!(!f32::NAN.ulps_eq(f32::NAN, 1.0, 4));
+    }
+
+    #[test]
+    fn composite_types_compare_every_component() {
+        let c = Complexh::new(1.0, -2.0);
+        assert!(c.abs_diff_eq(c + Complexh::new(0.0, 1e-10), 1e-9));
+        assert!(!c.abs_diff_eq(c + Complexh::new(1e-8, 0.0), 1e-9));
+
+        let v = Vec3h::new(1.0, 2.0, 3.0);
+        assert!(v.abs_diff_eq(v + Vec3h::new(0.0, 1e-10, 0.0), 1e-9));
+        assert!(!v.abs_diff_eq(v + Vec3h::new(0.0, 0.0, 1e-8), 1e-9));
+        assert!(Vec4n::new(1.0, 2.0, 3.0, 4.0).ulps_eq(Vec4n::new(1.0, 2.0, 3.0, f32::from_bits(4.0f32.to_bits() + 1)), 0.0, 1));
+
+        let m = Mat4h::get_translation_mat(Vec3h::new(1.0, -2.0, 3.0)) * Mat4h::get_angle_axis_mat_deg(Vec3h::up(), 30.0);
+        let mut off = m;
+        off[2][3] += 1e-6;
+        assert!(m.relative_eq(off, 0.0, 1e-6));
+        assert!(!m.relative_eq(off, 0.0, 1e-8));
+        assert!(Mat3h::identity().abs_diff_eq(Mat3h::from(m) * Mat3h::from(m).transpose(), 1e-12));
+        assert!(Mat2n::identity().approx_eq(Mat2n::identity(), f32::DEF_EPSILON));
+        assert!(Mat4x3n::identity().abs_diff_eq(Mat4x3n::from(Mat4n::identity()), 0.0));
+
+        let q = Quath::get_quat_from_angle_axis(0.7, Vec3h::new(1.0, -1.0, 0.5).normalized());
+        assert!(q.abs_diff_eq(q * Quath::identity(), 1e-12));
+        assert!(!q.abs_diff_eq(-q, 1e-12));
+        assert!(q.rotation_approx_eq(-q, 1e-12));
+        assert!(!q.rotation_approx_eq(q.conjugate(), 1e-12));
+    }
+
+    #[test]
+    fn assert_macros() {
+        let q = Quatn::get_quat_from_angle_axis(1.2, Vec3n::forward());
+        assert_approx_eq!(1.0f64, 1.0 + 1e-16);
+        assert_approx_eq!(Vec3n::new(1.0, 2.0, 3.0), Vec3n::new(1.0, 2.0, 3.000001));
+        assert_approx_eq!(Mat3h::identity(), Mat3h::get_angle_axis_mat_deg(Vec3h::up(), 1e-3), 1e-4);
+        assert_abs_diff_eq!(Vec2h::new(0.0, 1e-13), Vec2h::zero(), 1e-12);
+        assert_abs_diff_eq!(q * q.inverse(), Quatn::identity());
+        assert_relative_eq!(Vec3h::all(1e8), Vec3h::all(1e8 + 1.0), 0.0, 1e-7);
+        assert_ulps_eq!(0.1f32 + 0.2, 0.3f32, 0.0, 1);
+        assert_rotation_approx_eq!(q, -q);
+        assert_rotation_approx_eq!(q, -q, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "left ≈ right")]
+    fn assert_macros_fail() {
+        assert_abs_diff_eq!(Vec3h::new(1.0, 2.0, 3.0), Vec3h::new(1.0, 2.0, 3.1), 1e-3);
+    }
+
+    //Types outside of straal only have to implement approx_eq, the other comparisons are in ApproxEqualExt
+    #[derive(Copy, Clone)]
+    struct Meters(f64);
+
+    impl ApproxEqual<f64> for Meters {
+        fn approx_eq(self, rhs: Meters, epsilon: f64) -> bool {
+            self.0.approx_eq(rhs.0, epsilon)
+        }
+    }
+
+    impl ApproxEqualExt<f64> for Meters {
+        fn abs_diff_eq(self, rhs: Meters, epsilon: f64) -> bool {
+            self.0.abs_diff_eq(rhs.0, epsilon)
+        }
+
+        fn relative_eq(self, rhs: Meters, epsilon: f64, max_relative: f64) -> bool {
+            self.0.relative_eq(rhs.0, epsilon, max_relative)
+        }
+
+        fn ulps_eq(self, rhs: Meters, epsilon: f64, max_ulps: u32) -> bool {
+            self.0.ulps_eq(rhs.0, epsilon, max_ulps)
+        }
+    }
+
+    #[test]
+    fn external_implementations() {
+        assert!(Meters(2.0).approx_eq(Meters(2.0 + 1e-16), f64::DEF_EPSILON));
+        assert!(Meters(2.0).relative_eq(Meters(2.1), 0.0, 0.1));
+        assert!(!Meters(2.0).relative_eq(Meters(2.1), 0.0, 0.01));
+        assert!(!Meters(2.0).ulps_eq(Meters(2.1), 1e-9, 4));
+    }
+}
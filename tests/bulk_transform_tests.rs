@@ -0,0 +1,101 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod bulk_transform_tests {
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    fn get_points() -> Vec<Vec3h> {
+        (0..20).map(|i| {
+            let f = i as f64;
+            Vec3h::new(f.sin() * 3.0, f * 0.25 - 1.0, (f * 0.7).cos() + 0.5)
+        }).collect()
+    }
+
+    fn get_trs_mat() -> Mat4h {
+        let mut m = Mat4h::get_translation_mat(Vec3h::new(1.0, -2.0, 3.0));
+        m.rotate_around_axis_deg(Vec3h::new(1.0, 2.0, 3.0).normalized(), 40.0);
+        m.scale_uniformly(Vec3h::new(2.0, 0.5, 3.0));
+        m
+    }
+
+    #[test]
+    fn mat4_points_and_vectors() {
+        let m = get_trs_mat();
+        let points = get_points();
+        let mut out = vec![Vec3h::zero(); points.len()];
+        let mut in_place = points.clone();
+
+        m.transform_points(&points, &mut out);
+        m.transform_points_in_place(&mut in_place);
+        for (i, p) in points.iter().enumerate() {
+            assert_close_eps(Vec3h::from(m * Vec4h::from((*p, 1.0))), out[i], 1e-12);
+            assert_eq!(out[i], in_place[i]);
+        }
+
+        let mut in_place = points.clone();
+        m.transform_vectors(&points, &mut out);
+        m.transform_vectors_in_place(&mut in_place);
+        for (i, v) in points.iter().enumerate() {
+            assert_close_eps(Vec3h::from(m * Vec4h::from((*v, 0.0))), out[i], 1e-12);
+            assert_eq!(out[i], in_place[i]);
+        }
+
+        //Through a projection, the perspective divide puts the near and far planes at the edges of the depth range
+        let proj = Mat4h::get_perspective_mat_deg(90.0, 1.0, 1.0, 10.0, Handedness::Right, DepthRange::ZeroToOne);
+        let mut clip = [Vec3h::new(0.5, 0.5, -1.0), Vec3h::new(-4.0, 2.0, -10.0)];
+        proj.transform_points_perspective_in_place(&mut clip);
+        assert_close_eps(Vec3h::new(0.5, 0.5, 0.0), clip[0], 1e-12);
+        assert_close_eps(Vec3h::new(-0.4, 0.2, 1.0), clip[1], 1e-12);
+    }
+
+    #[test]
+    fn normals_stay_perpendicular() {
+        let m = get_trs_mat();
+        let tangents = get_points();
+        let normals: Vec<Vec3h> = tangents.iter().map(|t| t.cross(Vec3h::new(0.3, 1.0, -0.2)).normalized()).collect();
+        let mut out = vec![Vec3h::zero(); normals.len()];
+        let mut tangents_out = vec![Vec3h::zero(); tangents.len()];
+        m.transform_normals(&normals, &mut out);
+        m.transform_vectors(&tangents, &mut tangents_out);
+        let mut in_place = normals.clone();
+        Mat3h::from(m).transform_normals_in_place(&mut in_place);
+        for i in 0..normals.len() {
+            assert!(out[i].dot(tangents_out[i]).abs() < 1e-12);
+            assert!(out[i].is_unit());
+            assert_eq!(out[i], in_place[i]);
+        }
+    }
+
+    #[test]
+    fn quat_and_mat3_match_single_transforms() {
+        let q = Quath::get_quat_from_angle_axis(0.9, Vec3h::new(1.0, -2.0, 0.5).normalized());
+        let m = Mat3h::get_angle_axis_mat_rad(Vec3h::new(-1.0, 0.0, 2.0).normalized(), 1.3) * 2.0;
+        let points = get_points();
+        let mut out = vec![Vec3h::zero(); points.len()];
+        let mut in_place = points.clone();
+
+        q.transform_points(&points, &mut out);
+        q.transform_vectors_in_place(&mut in_place);
+        for (i, p) in points.iter().enumerate() {
+            assert_close_eps(q * *p, out[i], 1e-12);
+            assert_close_eps(q * *p, in_place[i], 1e-12);
+        }
+        q.transform_normals(&points, &mut out);
+        assert_close_eps(q * points[3], out[3], 1e-12);
+
+        m.transform_points(&points, &mut out);
+        for (i, p) in points.iter().enumerate() {
+            assert_close_eps(m * *p, out[i], 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_out_slice() {
+        let points = get_points();
+        let mut out = vec![Vec3h::zero(); points.len() - 1];
+        Mat4h::identity().transform_points(&points, &mut out);
+    }
+}
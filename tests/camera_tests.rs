@@ -0,0 +1,68 @@
+#[cfg(test)]
+pub mod camera_tests {
+    use straal::*;
+
+    #[test]
+    fn look_at_moves_target_onto_view_axis() {
+        let eye = Vec3h::new(3.0, 2.0, -5.0);
+        let target = Vec3h::new(-1.0, 0.5, 4.0);
+
+        let lh = Mat4h::look_at(eye, target, Vec3h::up(), Handedness::Left);
+        let dist = (target - eye).length();
+        assert_eq!(Vec4h::new(0.0, 0.0, dist, 1.0), lh * Vec4h::from((target, 1.0)));
+        assert_eq!(Vec4h::new(0.0, 0.0, 0.0, 1.0), lh * Vec4h::from((eye, 1.0)));
+
+        let rh = Mat4h::look_at(eye, target, Vec3h::up(), Handedness::Right);
+        assert_eq!(Vec4h::new(0.0, 0.0, -dist, 1.0), rh * Vec4h::from((target, 1.0)));
+    }
+
+    #[test]
+    fn look_at_keeps_right_on_x() {
+        let rh = Mat4h::look_to(Vec3h::zero(), -Vec3h::forward(), Vec3h::up(), Handedness::Right);
+        assert_eq!(Mat4h::identity(), rh);
+
+        let lh = Mat4h::look_to(Vec3h::zero(), Vec3h::forward(), Vec3h::up(), Handedness::Left);
+        assert_eq!(Mat4h::identity(), lh);
+    }
+
+    #[test]
+    fn camera_view_matches_look_at() {
+        let proj = Mat4h::get_perspective_mat_deg(60.0, 1.5, 0.1, 100.0, Handedness::Right, DepthRange::ZeroToOne);
+        let eye = Vec3h::new(1.0, 2.0, 3.0);
+        let camera = Camerah::new_looking_at(eye, Vec3h::zero(), Vec3h::up(), proj, Handedness::Right);
+        let view = Mat4h::look_at(eye, Vec3h::zero(), Vec3h::up(), Handedness::Right);
+        assert_eq!(view, camera.get_view_mat());
+        assert_eq!(proj * view, camera.get_view_projection_mat());
+    }
+
+    #[test]
+    fn fps_rotation() {
+        let mut camera = Camerah::new(Vec3h::zero(), Quath::identity(), Mat4h::identity(), Handedness::Right);
+        assert_eq!(-Vec3h::forward(), camera.forward());
+
+        camera.rotate_fps(std::f64::consts::FRAC_PI_2, 0.0);
+        assert_eq!(Vec3h::right(), camera.forward());
+
+        camera.rotate_fps(0.0, std::f64::consts::FRAC_PI_4);
+        assert_eq!(Vec3h::new(1.0, 1.0, 0.0).normalized(), camera.forward());
+
+        //Pitch gets clamped before reaching straight up
+        camera.rotate_fps(0.0, 10.0);
+        assert!(camera.forward().y < 1.0 && camera.forward().y > 0.99);
+        assert!(camera.up().y > 0.0);
+    }
+
+    #[test]
+    fn orbit_keeps_looking_at_target() {
+        let target = Vec3h::new(1.0, 0.0, 1.0);
+        let mut camera = Camerah::new_looking_at(Vec3h::new(1.0, 0.0, 6.0), target, Vec3h::up(), Mat4h::identity(), Handedness::Left);
+        camera.orbit(target, 0.7, 0.3);
+        camera.orbit(target, -0.2, 0.4);
+        assert!((camera.position - target).length().approx_eq(5.0, 1e-9));
+        assert_eq!((target - camera.position).normalized(), camera.forward());
+
+        camera.arcball(target, Vec2h::new(0.0, 0.0), Vec2h::new(0.3, -0.2));
+        assert!((camera.position - target).length().approx_eq(5.0, 1e-9));
+        assert_eq!((target - camera.position).normalized(), camera.forward());
+    }
+}
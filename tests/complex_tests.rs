@@ -0,0 +1,78 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod complex_tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    #[test]
+    fn complex_arithmetic() {
+        let a = Complexh::new(3.0, 4.0);
+        let b = Complexh::new(1.0, -2.0);
+        assert_eq!(Complexh::new(4.0, 2.0), a + b);
+        assert_eq!(Complexh::new(2.0, 6.0), a - b);
+        assert_eq!(Complexh::new(11.0, -2.0), a * b);
+        assert_eq!(a, (a * b) / b);
+        assert_eq!(Complexh::new(-1.0, 2.0), a / b);
+        assert_eq!(5.0, a.magnitude());
+        assert_eq!(Complexh::identity(), a * a.inverse());
+        assert_eq!(Complexh::new(3.0, -4.0), a.conjugate());
+        assert_eq!(-Complexh::identity(), Complexh::i() * Complexh::i());
+    }
+
+    #[test]
+    fn complex_polar_exp_and_powers() {
+        let c = Complexh::from_polar(2.0, FRAC_PI_4);
+        let (r, theta) = c.to_polar();
+        assert!(r.approx_eq(2.0, 1e-12) && theta.approx_eq(FRAC_PI_4, 1e-12));
+
+        //Euler's identity
+        assert_close(-Complexh::identity(), (Complexh::i() * PI).exp());
+        assert_eq!(c, c.ln().exp());
+
+        assert_eq!(c * c * c, c.powi(3));
+        assert_eq!(c.inverse() * c.inverse(), c.powi(-2));
+        assert_close(c * c, c.powf(2.0));
+        assert_close(c, c.sqrt() * c.sqrt());
+        //i^i is real
+        assert_close(Complexh::new((-FRAC_PI_2).exp(), 0.0), Complexh::i().powc(Complexh::i()));
+
+        let z = Complexh::new(-8.0, 0.0);
+        for k in 0..3 {
+            assert_close(z, z.nth_root(3, k).powi(3));
+        }
+        assert_close(Complexh::new(1.0, 3.0f64.sqrt()), z.nth_root(3, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "n > 0")]
+    fn zeroth_root() {
+        Complexh::new(2.0, 1.0).nth_root(0, 0);
+    }
+
+    #[test]
+    fn complex_rotations() {
+        let rot = Complexh::get_complex_from_angle_deg(90.0);
+        assert_eq!(Vec2h::up(), rot * Vec2h::right());
+        assert!(rot.get_angle_deg().approx_eq(90.0, 1e-12));
+
+        //Matches the rotation matrices and converts back losslessly
+        let theta = 0.7;
+        let c = Complexh::get_complex_from_angle_rad(theta);
+        let m = Mat2h::get_rotation_base(theta);
+        assert_eq!(m, Mat2h::from(c));
+        assert_eq!(c, Complexh::from(m));
+        let v = Vec2h::new(2.0, -3.0);
+        assert_eq!(v * m, c * v);
+
+        let a = Complexh::get_complex_from_angle_rad(-3.0);
+        let b = Complexh::get_complex_from_angle_rad(3.0);
+        //Takes the short way around, through pi
+        assert_close(Complexh::get_complex_from_angle_rad(PI), a.slerp(b, 0.5));
+        assert_close(Complexh::get_complex_from_angle_rad(PI), a.lerp(b, 0.5));
+        assert_eq!(Vec2h::new(0.6, 0.8), Vec2h::from(Complexh::from(Vec2h::new(0.6, 0.8))));
+    }
+}
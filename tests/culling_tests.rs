@@ -0,0 +1,109 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod culling_tests {
+    use straal::*;
+    use straal::geometry::*;
+
+    use crate::test_helpers::*;
+
+    fn get_view_projection(hand: Handedness, depth: DepthRange) -> Mat4h {
+        let projection = Mat4h::get_perspective_mat_deg(90.0, 1.0, 1.0, 10.0, hand, depth);
+        let forward = Vec3h::new(0.0, 0.0, 1.0) * if hand == Handedness::Left { 1.0 } else { -1.0 };
+        let view = Mat4h::look_to(Vec3h::new(5.0, 0.0, 0.0), forward, Vec3h::up(), hand);
+        projection * view
+    }
+
+    #[test]
+    fn frustum_extraction() {
+        for &hand in [Handedness::Left, Handedness::Right].iter() {
+            for &depth in [DepthRange::NegOneToOne, DepthRange::ZeroToOne].iter() {
+                let frustum = Frustumh::from_view_projection_mat(get_view_projection(hand, depth), depth);
+                let z = if hand == Handedness::Left { 1.0 } else { -1.0 };
+                let corners = frustum.corners().unwrap();
+                assert_close(Vec3h::new(4.0, -1.0, z), corners[0]);
+                assert_close(Vec3h::new(15.0, 10.0, 10.0 * z), corners[7]);
+                assert!((frustum.near.signed_distance(Vec3h::new(5.0, 0.0, 2.0 * z)) - 1.0).abs() < 1e-9);
+                assert!((frustum.far.unwrap().signed_distance(Vec3h::new(5.0, 0.0, 2.0 * z)) - 8.0).abs() < 1e-9);
+
+                assert!(frustum.contains_point(Vec3h::new(6.0, -2.0, 5.0 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(5.0, 0.0, -5.0 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(5.0, 0.0, 11.0 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(5.0, 3.0, 2.0 * z)));
+            }
+        }
+    }
+
+    #[test]
+    fn frustum_extraction_special_projections() {
+        let infinite = Mat4h::get_infinite_perspective_mat_deg(90.0, 1.0, 1.0, Handedness::Left, DepthRange::NegOneToOne);
+        let frustum = Frustumh::from_view_projection_mat(infinite, DepthRange::NegOneToOne);
+        assert!(frustum.contains_point(Vec3h::new(0.0, 0.0, 1e6)));
+        assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, 0.5)));
+        assert!(frustum.far.is_none());
+        assert!(frustum.corners().is_none());
+        assert_eq!(5, frustum.get_planes().count());
+        //Without a far plane there is nothing to turn into NaN
+        let moved = frustum.transformed(Mat4h::get_translation_mat(Vec3h::new(0.0, 0.0, 3.0)));
+        assert!(moved.far.is_none());
+        assert!(moved.get_planes().all(|plane| plane.normal.is_unit() && plane.d.is_finite()));
+        assert!(moved.contains_point(Vec3h::new(0.0, 0.0, 1e6)));
+        assert!(!moved.contains_point(Vec3h::new(0.0, 0.0, 3.5)));
+        assert_eq!(Containment::Inside, moved.classify_sphere(&Sphereh::new(Vec3h::new(0.0, 0.0, 1e5), 1.0)));
+        assert_eq!(Containment::Outside, moved.classify_sphere(&Sphereh::new(Vec3h::new(0.0, 0.0, 0.0), 1.0)));
+
+        let reversed = Mat4h::get_reversed_z_perspective_mat_deg(90.0, 1.0, 1.0, 10.0, Handedness::Left, DepthRange::ZeroToOne);
+        let frustum = Frustumh::from_view_projection_mat(reversed, DepthRange::ZeroToOne);
+        assert!(frustum.contains_point(Vec3h::new(0.0, 0.0, 5.0)));
+        assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, 0.5)));
+        assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, 11.0)));
+
+        let ortho = Mat4h::get_orthographic_mat(-2.0, 2.0, -1.0, 1.0, 0.0, 5.0, Handedness::Right, DepthRange::NegOneToOne);
+        let frustum = Frustumh::from_view_projection_mat(ortho, DepthRange::NegOneToOne);
+        assert!(frustum.contains_point(Vec3h::new(1.5, 0.5, -4.0)));
+        assert!(!frustum.contains_point(Vec3h::new(2.5, 0.5, -4.0)));
+        assert!(!frustum.contains_point(Vec3h::new(1.5, 0.5, 1.0)));
+    }
+
+    #[test]
+    fn frustum_extraction_reversed_z() {
+        for &hand in [Handedness::Left, Handedness::Right].iter() {
+            for &depth in [DepthRange::NegOneToOne, DepthRange::ZeroToOne].iter() {
+                let z = if hand == Handedness::Left { 1.0 } else { -1.0 };
+
+                let finite = Mat4h::get_reversed_z_perspective_mat_deg(90.0, 1.0, 1.0, 10.0, hand, depth);
+                let frustum = Frustumh::from_view_projection_mat(finite, depth);
+                assert!((frustum.near.signed_distance(Vec3h::new(0.0, 0.0, 2.0 * z)) - 1.0).abs() < 1e-9);
+                assert!((frustum.far.unwrap().signed_distance(Vec3h::new(0.0, 0.0, 2.0 * z)) - 8.0).abs() < 1e-9);
+                assert!(frustum.contains_point(Vec3h::new(0.0, 0.0, 5.0 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, 0.5 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, 11.0 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, -5.0 * z)));
+
+                let infinite = Mat4h::get_infinite_reversed_z_perspective_mat_deg(90.0, 1.0, 1.0, hand, depth);
+                let frustum = Frustumh::from_view_projection_mat(infinite, depth);
+                assert!(frustum.far.is_none());
+                assert!(frustum.get_planes().all(|plane| plane.normal.is_unit() && plane.d.is_finite()));
+                assert!((frustum.near.signed_distance(Vec3h::new(0.0, 0.0, 2.0 * z)) - 1.0).abs() < 1e-9);
+                assert!(frustum.contains_point(Vec3h::new(0.0, 0.0, 1e6 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, 0.5 * z)));
+                assert!(!frustum.contains_point(Vec3h::new(0.0, 0.0, -5.0 * z)));
+                assert_eq!(Containment::Inside, frustum.classify_sphere(&Sphereh::new(Vec3h::new(0.0, 0.0, 1e5 * z), 1.0)));
+                assert_eq!(Containment::Outside, frustum.classify_sphere(&Sphereh::new(Vec3h::new(0.0, 0.0, -5.0 * z), 1.0)));
+            }
+        }
+    }
+
+    #[test]
+    fn frustum_classification() {
+        let frustum = Frustumh::from_view_projection_mat(get_view_projection(Handedness::Left, DepthRange::ZeroToOne), DepthRange::ZeroToOne);
+
+        assert_eq!(Containment::Inside, frustum.classify_sphere(&Sphereh::new(Vec3h::new(5.0, 0.0, 5.0), 1.0)));
+        assert_eq!(Containment::Intersecting, frustum.classify_sphere(&Sphereh::new(Vec3h::new(5.0, 0.0, 10.0), 1.0)));
+        assert_eq!(Containment::Outside, frustum.classify_sphere(&Sphereh::new(Vec3h::new(5.0, 0.0, -1.0), 1.0)));
+
+        assert_eq!(Containment::Inside, frustum.classify_aabb(&Aabbh::new(Vec3h::new(4.0, -1.0, 4.0), Vec3h::new(6.0, 1.0, 6.0))));
+        assert_eq!(Containment::Intersecting, frustum.classify_aabb(&Aabbh::new(Vec3h::new(4.0, -1.0, 0.0), Vec3h::new(6.0, 1.0, 2.0))));
+        assert_eq!(Containment::Outside, frustum.classify_aabb(&Aabbh::new(Vec3h::new(12.0, -1.0, 2.0), Vec3h::new(14.0, 1.0, 4.0))));
+    }
+}
@@ -0,0 +1,54 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod decomposition_tests {
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    #[test]
+    fn decompose_trs() {
+        let axis = Vec3h::new(1.0, 1.0, 0.0).normalized();
+        let mut m = Mat4h::identity();
+        m.translate(Vec3h::new(1.0, 2.0, 3.0));
+        m.rotate_around_axis_rad(axis, 0.8);
+        m.scale_uniformly(Vec3h::new(2.0, 3.0, 4.0));
+
+        let d = m.decompose().unwrap();
+        assert_close(Vec3h::new(1.0, 2.0, 3.0), d.translation);
+        assert_close(Vec3h::new(2.0, 3.0, 4.0), d.scale);
+        assert_close(Vec3h::zero(), d.shear);
+        assert!(!d.has_shear());
+        assert_eq!(Mat3h::get_angle_axis_mat_rad(axis, 0.8), d.get_rotation_mat());
+        assert_eq!(m, d.recompose());
+        assert_eq!(m, Mat4h::recompose(d.translation, d.rotation, d.scale, d.shear));
+    }
+
+    #[test]
+    fn decompose_negative_scale_and_shear() {
+        let rotation = Quath::get_quat_from_angle_axis(1.2, Vec3h::new(0.0, 1.0, 2.0).normalized());
+        let d = Decompositionh::new(Vec3h::new(-1.0, 0.0, 5.0), rotation, Vec3h::new(-2.0, 0.5, 1.5), Vec3h::new(0.3, -0.2, 0.7));
+        let m = d.recompose();
+        assert!(m.determinant() < 0.0);
+
+        let decomposed = m.decompose().unwrap();
+        assert_eq!(d, decomposed);
+        assert!(decomposed.has_shear());
+        assert!((decomposed.get_rotation_mat().determinant() - 1.0).abs() < 1e-9);
+
+        //Mirroring along y ends up in the x scale, the rotation makes up for it
+        let mirrored = Mat3h::get_uniform_scale_mat(Vec3h::new(1.0, -1.0, 1.0));
+        let decomposed = mirrored.decompose().unwrap();
+        assert_close(Vec3h::new(-1.0, 1.0, 1.0), decomposed.scale);
+        assert_eq!(mirrored, Mat3h::from(decomposed));
+        assert_eq!(mirrored, Mat3h::recompose(decomposed.rotation, decomposed.scale, decomposed.shear));
+    }
+
+    #[test]
+    fn decompose_failures() {
+        assert!(Mat4h::get_uniform_scale_mat(Vec3h::new(1.0, 0.0, 1.0)).decompose().is_none());
+        assert!(Mat3h::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0).decompose().is_none());
+        let projection = Mat4h::get_perspective_mat_deg(60.0, 1.0, 0.1, 100.0, Handedness::Right, DepthRange::ZeroToOne);
+        assert!(projection.decompose().is_none());
+    }
+}
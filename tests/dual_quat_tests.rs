@@ -0,0 +1,97 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod dual_quat_tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    fn get_test_dual_quat() -> DualQuath {
+        DualQuath::from_rotation_translation(Quath::get_quat_from_angle_axis(0.7, Vec3h::new(1.0, 2.0, -1.0).normalized()),
+                                             Vec3h::new(1.0, -2.0, 3.0))
+    }
+
+    #[test]
+    fn dual_quat_transforms() {
+        let dq = get_test_dual_quat();
+        assert!(dq.is_unit());
+        assert_close(Vec3h::new(1.0, -2.0, 3.0), dq.get_translation());
+
+        let p = Vec3h::new(4.0, 5.0, -6.0);
+        let q = dq.get_rotation();
+        assert_close(q * p + Vec3h::new(1.0, -2.0, 3.0), dq * p);
+        assert_close(q * p, dq.transform_vector(p));
+
+        //Composes like matrices, rhs first
+        let other = DualQuath::from_rotation_translation(Quath::get_quat_from_angle_axis(FRAC_PI_2, Vec3h::up()), Vec3h::new(0.0, 1.0, 0.0));
+        assert_close(dq * (other * p), (dq * other) * p);
+        assert_close(p, (!dq) * (dq * p));
+        assert_close(p, dq.conjugate() * (dq * p));
+        assert_eq!(DualQuath::identity(), dq * dq.inverse());
+
+        //Normalizing a scaled dual quaternion with a dual part that is not perpendicular to the real part gives back the same transform
+        let mut off = dq * 3.0;
+        off.dual += off.real * 0.1;
+        assert!(!off.is_unit());
+        assert!(off.normalized().is_unit());
+        assert_close(dq * p, off.normalized() * p);
+    }
+
+    #[test]
+    fn dual_quat_mat4_conversions() {
+        let dq = get_test_dual_quat();
+        let m = Mat4h::from(dq);
+        let p = Vec3h::new(4.0, 5.0, -6.0);
+        assert_close(dq * p, (m * Vec4h::new(p.x, p.y, p.z, 1.0)).xyz());
+
+        let back = DualQuath::try_from_mat4(m).unwrap();
+        assert!(back == dq || back == -dq);
+        assert_close(dq * p, DualQuath::from(Transformh::from(dq)) * p);
+
+        //The scale gets dropped
+        let scaled = m * Mat4h::get_uniform_scale_mat(Vec3h::new(2.0, 2.0, 2.0));
+        assert_close(dq * p, DualQuath::try_from_mat4(scaled).unwrap() * p);
+        assert!(DualQuath::try_from_mat4(Mat4h::get_uniform_scale_mat(Vec3h::zero())).is_none());
+    }
+
+    #[test]
+    fn dual_quat_interpolation() {
+        let a = DualQuath::identity();
+        let b = DualQuath::from_rotation_translation(Quath::get_quat_from_angle_axis(FRAC_PI_2, Vec3h::up()), Vec3h::new(0.0, 4.0, 0.0));
+
+        //A quarter turn around y while moving up along y is a single screw motion, so halfway is half of both
+        let half = a.sclerp(b, 0.5);
+        assert_close(Vec3h::new(0.0, 2.0, 0.0), half.get_translation());
+        assert_eq!(Quath::get_quat_from_angle_axis(FRAC_PI_2 * 0.5, Vec3h::up()), half.get_rotation());
+        assert_eq!(a, a.sclerp(b, 0.0));
+        assert_eq!(b, a.sclerp(b, 1.0));
+        assert_eq!(b, b.pow(0.5) * b.pow(0.5));
+
+        //A screw around an axis that does not go through the origin keeps points on that axis in place
+        let around = DualQuath::from_translation(Vec3h::new(1.0, 0.0, 0.0)) *
+            DualQuath::from_rotation(Quath::get_quat_from_angle_axis(FRAC_PI_2, Vec3h::up())) *
+            DualQuath::from_translation(Vec3h::new(-1.0, 0.0, 0.0));
+        assert_close(Vec3h::new(1.0, 3.0, 0.0), DualQuath::identity().sclerp(around, 0.3) * Vec3h::new(1.0, 3.0, 0.0));
+
+        //Blending ignores the sign of the inputs
+        let blended = DualQuath::blend(&[a, -b], &[0.5, 0.5]);
+        assert!(blended.is_unit());
+        assert_eq!(a.lerp(b, 0.5), blended);
+        assert_close(Vec3h::new(0.0, 2.0, 0.0), blended.get_translation());
+        assert_eq!(b, DualQuath::blend(&[b], &[2.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one")]
+    fn blend_nothing() {
+        DualQuath::blend(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "one weight per dual quaternion")]
+    fn blend_missing_weights() {
+        DualQuath::blend(&[get_test_dual_quat(), DualQuath::identity()], &[1.0]);
+    }
+}
@@ -0,0 +1,194 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod euler_tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    const ORDERS: [RotationOrder; 12] = [RotationOrder::PHB, RotationOrder::PBH, RotationOrder::HPB, RotationOrder::HBP,
+        RotationOrder::BPH, RotationOrder::BHP, RotationOrder::PHP, RotationOrder::PBP,
+        RotationOrder::HPH, RotationOrder::HBH, RotationOrder::BPB, RotationOrder::BHB];
+
+    const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+    fn get_axis(letter: char) -> Vec3h {
+        match letter {
+            'P' => Vec3h::right(),
+            'H' => Vec3h::up(),
+            _ => Vec3h::forward(),
+        }
+    }
+
+    fn assert_same_rotation(expected: Quath, actual: Quath, epsilon: f64) {
+        assert!(expected.dot(actual).abs() > 1.0 - epsilon, "expected {}, got {}", expected, actual);
+    }
+
+    //Angles that stay inside the ranges that get_euler_angles_rad returns, plus the edges and gimbal lock
+    fn get_test_angles(order: RotationOrder) -> Vec<Vec3h> {
+        let middle = if order.is_proper_euler() {
+            [0.0, 0.3, 1.2, FRAC_PI_2, 2.5, PI]
+        } else {
+            [-FRAC_PI_2, -1.2, -0.3, 0.0, 0.9, FRAC_PI_2]
+        };
+        let outer = [-3.0, -2.1, -0.7, 0.0, 0.4, 1.6, 2.9, PI];
+        let mut angles = Vec::new();
+        for &first in outer.iter() {
+            for &second in middle.iter() {
+                for &third in outer.iter() {
+                    angles.push(Vec3h::new(first, second, third));
+                }
+            }
+        }
+        angles
+    }
+
+    #[test]
+    fn flex_euler_matches_single_axis_products() {
+        let by_axis = Vec3h::new(0.3, -1.1, 2.0);
+        let names = ["PHB", "PBH", "HPB", "HBP", "BPH", "BHP"];
+        for (order, name) in ORDERS.iter().zip(names.iter()) {
+            let mut q = Quath::identity();
+            let mut m = Mat3h::identity();
+            for letter in name.chars() {
+                let angle = by_axis[match letter { 'P' => 0, 'H' => 1, _ => 2 }];
+                q *= Quath::get_quat_from_angle_axis(angle, get_axis(letter));
+                m *= Mat3h::get_angle_axis_mat_rad(get_axis(letter), angle);
+            }
+            assert_eq!(q, Quath::get_quat_flex_euler_rad(by_axis.x, by_axis.y, by_axis.z, *order), "{:?}", order);
+            assert_close_eps(m, Mat3h::get_rotation_mat_flex_euler_rad(by_axis, *order), 1e-12);
+        }
+    }
+
+    #[test]
+    fn intrinsic_and_extrinsic_orders() {
+        let angles = Vec3h::new(0.4, -0.9, 2.2);
+        for &order in ORDERS.iter() {
+            let axes = order.get_axes();
+            let a = Quath::get_quat_from_angle_axis(angles.x, get_axis("PHB".chars().nth(axes[0]).unwrap()));
+            let b = Quath::get_quat_from_angle_axis(angles.y, get_axis("PHB".chars().nth(axes[1]).unwrap()));
+            let c = Quath::get_quat_from_angle_axis(angles.z, get_axis("PHB".chars().nth(axes[2]).unwrap()));
+            assert_eq!(a * b * c, Quath::get_quat_from_euler_rad(angles, order, EulerFrame::Intrinsic));
+            assert_eq!(c * b * a, Quath::get_quat_from_euler_rad(angles, order, EulerFrame::Extrinsic));
+            assert_close_eps(Mat3h::from(a * b * c), Mat3h::get_rotation_mat_from_euler_rad(angles, order, EulerFrame::Intrinsic), 1e-12);
+        }
+
+        //ZXZ, the classic proper Euler order
+        let zxz = Quath::get_quat_from_euler_deg(Vec3h::new(90.0, 90.0, 0.0), RotationOrder::BPB, EulerFrame::Intrinsic);
+        assert!((Vec3h::new(0.0, 0.0, 1.0) - zxz * Vec3h::up()).length() < 1e-12);
+    }
+
+    #[test]
+    fn euler_round_trips() {
+        for &order in ORDERS.iter() {
+            for &frame in FRAMES.iter() {
+                for angles in get_test_angles(order) {
+                    let q = Quath::get_quat_from_euler_rad(angles, order, frame);
+                    let back = q.get_euler_angles_rad(order, frame);
+                    assert_same_rotation(q, Quath::get_quat_from_euler_rad(back, order, frame), 1e-12);
+
+                    let m = Mat3h::get_rotation_mat_from_euler_rad(angles, order, frame);
+                    let back_m = m.get_euler_angles_rad(order, frame);
+                    assert_same_rotation(q, Quath::get_quat_from_euler_rad(back_m, order, frame), 1e-12);
+
+                    //Away from gimbal lock and the edges, the angles themselves come back as well
+                    let middle_edge = if order.is_proper_euler() { angles.y.min(PI - angles.y) } else { FRAC_PI_2 - angles.y.abs() };
+                    if middle_edge > 1e-3 && angles.x.abs() < PI && angles.z.abs() < PI {
+                        assert!((angles - back).length() < 1e-9, "{:?} {:?}: {} became {}", order, frame, angles, back);
+                        assert!((angles - back_m).length() < 1e-9, "{:?} {:?}: {} became {}", order, frame, angles, back_m);
+                    } else if middle_edge == 0.0 {
+                        assert_eq!(0.0, back.z, "{:?} {:?}: {} became {}", order, frame, angles, back);
+                    }
+
+                    //And in f32
+                    let qn = Quatn::get_quat_from_euler_rad(Vec3n::new(angles.x as f32, angles.y as f32, angles.z as f32), order, frame);
+                    let back_n = qn.get_euler_angles_rad(order, frame);
+                    assert!(qn.dot(Quatn::get_quat_from_euler_rad(back_n, order, frame)).abs() > 1.0 - 1e-5);
+                }
+            }
+        }
+    }
+
+    //xorshift64*, so the random sweep is the same on every run
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> f64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 / (1u64 << 53) as f64
+        }
+
+        fn range(&mut self, min: f64, max: f64) -> f64 {
+            min + (max - min) * self.next()
+        }
+    }
+
+    //The first and last angle anywhere in (-pi, pi), the middle one anywhere in the range of the order
+    fn get_random_angles(rng: &mut Rng, order: RotationOrder) -> Vec3h {
+        let (min, max) = if order.is_proper_euler() { (0.0, PI) } else { (-FRAC_PI_2, FRAC_PI_2) };
+        Vec3h::new(rng.range(-PI, PI), rng.range(min, max), rng.range(-PI, PI))
+    }
+
+    #[test]
+    fn euler_round_trips_random() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        for &order in ORDERS.iter() {
+            for &frame in FRAMES.iter() {
+                for _ in 0..500 {
+                    let angles = get_random_angles(&mut rng, order);
+                    let q = Quath::get_quat_from_euler_rad(angles, order, frame);
+                    let back = q.get_euler_angles_rad(order, frame);
+                    let back_m = Mat3h::get_rotation_mat_from_euler_rad(angles, order, frame).get_euler_angles_rad(order, frame);
+                    assert_same_rotation(q, Quath::get_quat_from_euler_rad(back, order, frame), 1e-12);
+                    assert_same_rotation(q, Quath::get_quat_from_euler_rad(back_m, order, frame), 1e-12);
+
+                    let middle_edge = if order.is_proper_euler() { angles.y.min(PI - angles.y) } else { FRAC_PI_2 - angles.y.abs() };
+                    if middle_edge > 1e-3 {
+                        assert!((angles - back).length() < 1e-9, "{:?} {:?}: {} became {}", order, frame, angles, back);
+                        assert!((angles - back_m).length() < 1e-9, "{:?} {:?}: {} became {}", order, frame, angles, back_m);
+                    }
+                }
+            }
+        }
+    }
+
+    //Just next to gimbal lock (on both sides of it) the angles can jump around, but the rotation has to stay the same
+    #[test]
+    fn euler_round_trips_near_gimbal_lock() {
+        let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+        for &order in ORDERS.iter() {
+            let locks = if order.is_proper_euler() { [0.0, PI] } else { [-FRAC_PI_2, FRAC_PI_2] };
+            for &frame in FRAMES.iter() {
+                for &lock in locks.iter() {
+                    for &offset in [-1e-5, -1e-7, -1e-10, 1e-10, 1e-7, 1e-5].iter() {
+                        for _ in 0..20 {
+                            let angles = Vec3h::new(rng.range(-PI, PI), lock + offset, rng.range(-PI, PI));
+                            let q = Quath::get_quat_from_euler_rad(angles, order, frame);
+                            let back = q.get_euler_angles_rad(order, frame);
+                            let back_m = Mat3h::get_rotation_mat_from_euler_rad(angles, order, frame).get_euler_angles_rad(order, frame);
+                            assert_same_rotation(q, Quath::get_quat_from_euler_rad(back, order, frame), 1e-12);
+                            assert_same_rotation(q, Quath::get_quat_from_euler_rad(back_m, order, frame), 1e-12);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn legacy_euler_angles() {
+        let m = Mat3h::get_rotation_mat_euler_upr_obj_deg(30.0, -40.0, 50.0);
+        assert_eq!(Vec3h::new(30.0, -40.0, 50.0), m.get_euler_angles_upr_obj_deg());
+        let m = Mat3h::get_rotation_mat_euler_obj_upr_deg(30.0, -40.0, 50.0);
+        assert_eq!(Vec3h::new(30.0, -40.0, 50.0), m.get_euler_angles_obj_upr_deg());
+        assert_close_eps(m.transpose(), Mat3h::get_rotation_mat_euler_upr_obj_deg(30.0, -40.0, 50.0), 1e-12);
+
+        //Gimbal lock puts all of the heading and bank into the heading
+        let m = Mat3h::get_rotation_mat_euler_obj_upr_deg(90.0, 20.0, 0.0);
+        assert_eq!(Vec3h::new(90.0, 20.0, 0.0), m.get_euler_angles_obj_upr_deg());
+    }
+}
@@ -0,0 +1,93 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod ga_tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use straal::ga::*;
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    #[test]
+    fn wedge_products() {
+        let a = Vec3h::new(1.0, 2.0, 3.0);
+        let b = Vec3h::new(-2.0, 0.5, 4.0);
+        assert_eq!(-a.wedge(b), b.wedge(a));
+        assert_eq!(Bivector3h::zero(), a.wedge(a * 2.0));
+        assert_eq!(a.cross(b), a.wedge(b).get_normal());
+        assert_eq!(a.wedge(b), Bivector3h::from_normal(a.cross(b)));
+        assert!((a.cross(b).length() - a.wedge(b).magnitude()).abs() < 1e-12);
+        assert_eq!(Bivector3h::xy(), Vec3h::right().wedge(Vec3h::up()));
+    }
+
+    #[test]
+    fn rotor3_rotations() {
+        //A quarter turn in the xy plane takes x to y
+        let r = Rotor3h::get_rotor_from_angle_plane(FRAC_PI_2, Bivector3h::xy());
+        assert_close(Vec3h::up(), r * Vec3h::right());
+        assert_close(Vec3h::new(0.0, 0.0, 5.0), r * Vec3h::new(0.0, 0.0, 5.0));
+
+        let plane = Vec3h::new(1.0, 2.0, -1.0).wedge(Vec3h::new(0.5, -1.0, 2.0)).normalized();
+        let (got_plane, got_theta) = Rotor3h::get_angle_plane_from_rotor(&Rotor3h::get_rotor_from_angle_plane(0.8, plane));
+        assert_eq!(plane, got_plane);
+        assert!((0.8 - got_theta).abs() < 1e-12);
+
+        let a = Rotor3h::get_rotor_from_angle_plane(0.8, plane);
+        let b = Rotor3h::get_rotor_from_angle_plane(-1.3, Bivector3h::zx());
+        let v = Vec3h::new(4.0, -5.0, 6.0);
+        assert_close(a * (b * v), (a * b) * v);
+        assert_close(v, a.reverse() * (a * v));
+        assert_close(v, (a * 2.0f64.sqrt()).inverse() * ((a * 2.0f64.sqrt()) * v));
+
+        let from = Vec3h::new(1.0, 1.0, 0.0);
+        let to = Vec3h::new(0.0, -3.0, 2.0);
+        assert_close(to.normalized(), Rotor3h::get_rotor_from_to(from, to) * from.normalized());
+        assert_close(-from.normalized(), Rotor3h::get_rotor_from_to(from, -from) * from.normalized());
+        assert_eq!(Rotor3h::get_rotor_from_angle_plane(0.4, plane), Rotor3h::identity().slerp(a, 0.5));
+    }
+
+    #[test]
+    fn rotor3_quat_and_mat3_conversions() {
+        let q = Quath::get_quat_from_angle_axis(0.7, Vec3h::new(1.0, 2.0, -1.0).normalized());
+        let p = Quath::get_quat_from_angle_axis(-1.9, Vec3h::new(0.0, 1.0, 3.0).normalized());
+        let v = Vec3h::new(4.0, -5.0, 6.0);
+        let r = Rotor3h::from(q);
+        assert_close(q * v, r * v);
+        assert_eq!(q, Quath::from(r));
+        assert_eq!(q * p, Quath::from(r * Rotor3h::from(p)));
+        assert_eq!(q.slerp(p, 0.3), Quath::from(r.slerp(Rotor3h::from(p), 0.3)));
+
+        //The plane of the rotor is the one perpendicular to the axis of the quaternion
+        assert_eq!(Rotor3h::get_rotor_from_angle_plane(FRAC_PI_2, Bivector3h::xy()),
+                   Rotor3h::from(Quath::get_quat_from_angle_axis(FRAC_PI_2, Vec3h::forward())));
+
+        assert_eq!(Mat3h::from(q), Mat3h::from(r));
+        let back = Rotor3h::from(Mat3h::from(r));
+        assert!(back == r || back == -r);
+    }
+
+    #[test]
+    fn rotor2_rotations() {
+        let r = Rotor2h::get_rotor_from_angle_deg(90.0);
+        assert_eq!(Vec2h::up(), r * Vec2h::right());
+        assert!((r.get_angle_deg() - 90.0).abs() < 1e-12);
+
+        let c = Complexh::get_complex_from_angle_rad(0.7);
+        let v = Vec2h::new(2.0, -3.0);
+        assert_eq!(c * v, Rotor2h::from(c) * v);
+        assert_eq!(c, Complexh::from(Rotor2h::from(c)));
+        assert_eq!(Mat2h::from(c), Mat2h::from(Rotor2h::from(c)));
+
+        let from = Vec2h::new(1.0, 1.0);
+        let to = Vec2h::new(-2.0, 0.5);
+        assert_eq!(to.normalized(), Rotor2h::get_rotor_from_to(from, to) * from.normalized());
+
+        //Takes the short way around, through pi
+        let a = Rotor2h::get_rotor_from_angle_rad(-3.0);
+        let b = Rotor2h::get_rotor_from_angle_rad(3.0);
+        assert_eq!(-Vec2h::right(), a.slerp(b, 0.5) * Vec2h::right());
+        assert_eq!(-Vec2h::right(), a.lerp(b, 0.5) * Vec2h::right());
+        assert_eq!(v, (!r) * (r * v));
+    }
+}
@@ -0,0 +1,129 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod geometry_tests {
+    use straal::*;
+    use straal::geometry::*;
+
+    use crate::test_helpers::*;
+
+    #[test]
+    fn ray_segment_and_plane_queries() {
+        let ray = Rayh::new(Vec3h::zero(), Vec3h::new(0.0, 0.0, 2.0));
+        assert_close(Vec3h::new(0.0, 0.0, 3.0), ray.at(3.0));
+        assert_close(Vec3h::new(0.0, 0.0, 0.0), ray.closest_point(Vec3h::new(1.0, 0.0, -5.0)));
+        assert_eq!(1.0, ray.distance(Vec3h::new(1.0, 0.0, 4.0)));
+
+        let segment = LineSegmenth::new(Vec3h::new(-1.0, 0.0, 0.0), Vec3h::new(1.0, 0.0, 0.0));
+        assert_close(Vec3h::new(1.0, 0.0, 0.0), segment.closest_point(Vec3h::new(3.0, 1.0, 0.0)));
+        let other = LineSegmenth::new(Vec3h::new(0.5, 1.0, -1.0), Vec3h::new(0.5, 1.0, 1.0));
+        let (a, b) = segment.closest_points_to_segment(&other);
+        assert_close(Vec3h::new(0.5, 0.0, 0.0), a);
+        assert_close(Vec3h::new(0.5, 1.0, 0.0), b);
+
+        let plane = Planeh::from_point_normal(Vec3h::new(0.0, 2.0, 0.0), Vec3h::new(0.0, 3.0, 0.0));
+        assert_eq!(3.0, plane.signed_distance(Vec3h::new(1.0, 5.0, 1.0)));
+        assert_eq!(-2.0, plane.signed_distance(Vec3h::new(1.0, 0.0, 1.0)));
+        assert_close(Vec3h::new(1.0, 2.0, 1.0), plane.closest_point(Vec3h::new(1.0, 5.0, 1.0)));
+
+        let x = Planeh::new(Vec3h::new(1.0, 0.0, 0.0), 1.0);
+        let y = Planeh::new(Vec3h::new(0.0, 1.0, 0.0), 2.0);
+        let z = Planeh::new(Vec3h::new(0.0, 0.0, 1.0), 3.0);
+        assert_close(Vec3h::new(1.0, 2.0, 3.0), Planeh::intersect_planes(&x, &y, &z).unwrap());
+        assert!(Planeh::intersect_planes(&x, &x.flipped(), &z).is_none());
+    }
+
+    #[test]
+    fn sphere_aabb_and_obb_queries() {
+        let sphere = Sphereh::new(Vec3h::new(1.0, 0.0, 0.0), 2.0);
+        assert_eq!(1.0, sphere.signed_distance(Vec3h::new(4.0, 0.0, 0.0)));
+        assert_eq!(-2.0, sphere.signed_distance(Vec3h::new(1.0, 0.0, 0.0)));
+        assert_close(Vec3h::new(3.0, 0.0, 0.0), sphere.closest_point(Vec3h::new(5.0, 0.0, 0.0)));
+
+        let points = [Vec3h::new(1.0, -2.0, 0.0), Vec3h::new(-1.0, 2.0, 3.0), Vec3h::new(0.0, 0.0, -1.0)];
+        let aabb = Aabbh::from_points(&points);
+        assert_close(Vec3h::new(-1.0, -2.0, -1.0), aabb.min);
+        assert_close(Vec3h::new(1.0, 2.0, 3.0), aabb.max);
+        let bounding = Sphereh::from_points(&points);
+        assert!(points.iter().all(|&p| bounding.signed_distance(p) <= 1e-9));
+
+        let unit = Aabbh::new(Vec3h::new(1.0, 1.0, 1.0), Vec3h::new(-1.0, -1.0, -1.0));
+        assert_eq!(-1.0, unit.signed_distance(Vec3h::zero()));
+        assert_eq!(2.0, unit.signed_distance(Vec3h::new(3.0, 0.0, 0.0)));
+        assert!((unit.signed_distance(Vec3h::new(2.0, 2.0, 1.0)) - 2.0f64.sqrt()).abs() < 1e-9);
+        assert_close(Vec3h::new(1.0, 0.5, -1.0), unit.closest_point(Vec3h::new(4.0, 0.5, -3.0)));
+
+        let obb = Obbh::from_rotation(Vec3h::zero(), Quath::get_quat_from_angle_axis(std::f64::consts::FRAC_PI_4, Vec3h::forward()), Vec3h::new(1.0, 1.0, 1.0));
+        let corner = obb.corners().iter().fold(0.0f64, |max, c| max.max(c.x));
+        assert!((corner - 2.0f64.sqrt()).abs() < 1e-9);
+        assert!(obb.contains_point(Vec3h::new(1.3, 0.0, 0.0)));
+        assert!(!unit.contains_point(Vec3h::new(1.3, 0.0, 0.0)));
+        assert!((obb.signed_distance(Vec3h::new(3.0, 0.0, 0.0)) - (3.0 - 2.0f64.sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn capsule_and_triangle_queries() {
+        let capsule = Capsuleh::new_upright(Vec3h::zero(), 4.0, 1.0);
+        assert_close(Vec3h::new(0.0, 1.0, 0.0), capsule.segment.start);
+        assert_close(Vec3h::new(0.0, 3.0, 0.0), capsule.segment.end);
+        assert_eq!(1.0, capsule.signed_distance(Vec3h::new(2.0, 2.0, 0.0)));
+        assert_eq!(1.0, capsule.signed_distance(Vec3h::new(0.0, 5.0, 0.0)));
+        assert_close(Vec3h::new(0.0, 0.0, 0.0), capsule.closest_point(Vec3h::new(0.0, -3.0, 0.0)));
+
+        let triangle = Triangleh::new(Vec3h::zero(), Vec3h::new(1.0, 0.0, 0.0), Vec3h::new(0.0, 1.0, 0.0));
+        assert_close(Vec3h::new(0.0, 0.0, 1.0), triangle.normal());
+        assert_eq!(0.5, triangle.area());
+        assert_close(Vec3h::new(0.25, 0.25, 0.0), triangle.closest_point(Vec3h::new(0.25, 0.25, 2.0)));
+        assert_close(Vec3h::new(1.0, 0.0, 0.0), triangle.closest_point(Vec3h::new(3.0, -1.0, 0.0)));
+        assert_close(Vec3h::new(0.5, 0.5, 0.0), triangle.closest_point(Vec3h::new(1.0, 1.0, 0.0)));
+        assert_eq!(2.0, triangle.signed_distance(Vec3h::new(0.25, 0.25, 2.0)));
+        assert_eq!(-2.0, triangle.signed_distance(Vec3h::new(0.25, 0.25, -2.0)));
+        assert_close(Vec3h::new(0.5, 0.25, 0.25), triangle.barycentric(Vec3h::new(0.25, 0.25, 0.0)));
+    }
+
+    #[test]
+    fn frustum_queries() {
+        let planes = [
+            Planeh::new(Vec3h::new(1.0, 0.0, 0.0), -1.0),
+            Planeh::new(Vec3h::new(-1.0, 0.0, 0.0), -1.0),
+            Planeh::new(Vec3h::new(0.0, 1.0, 0.0), -1.0),
+            Planeh::new(Vec3h::new(0.0, -1.0, 0.0), -1.0),
+            Planeh::new(Vec3h::new(0.0, 0.0, 1.0), -1.0),
+            Planeh::new(Vec3h::new(0.0, 0.0, -1.0), -1.0),
+        ];
+        let frustum = Frustumh::from_planes(planes);
+        let corners = frustum.corners().unwrap();
+        assert_close(Vec3h::new(-1.0, -1.0, -1.0), corners[0]);
+        assert_close(Vec3h::new(1.0, 1.0, 1.0), corners[7]);
+
+        assert!(frustum.contains_point(Vec3h::new(0.5, 0.5, 0.5)));
+        assert!(!frustum.contains_point(Vec3h::new(1.5, 0.5, 0.5)));
+        assert_eq!(-0.5, frustum.signed_distance(Vec3h::new(0.5, 0.0, 0.0)));
+        assert_close(Vec3h::new(1.0, 0.5, 0.0), frustum.closest_point(Vec3h::new(3.0, 0.5, 0.0)));
+        assert_close(Vec3h::new(1.0, 1.0, 0.5), frustum.closest_point(Vec3h::new(2.0, 3.0, 0.5)));
+        assert_close(Vec3h::new(1.0, 1.0, 1.0), frustum.closest_point(Vec3h::new(2.0, 2.0, 2.0)));
+        assert!((frustum.signed_distance(Vec3h::new(2.0, 2.0, 2.0)) - 3.0f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn primitives_transformed() {
+        let m = Mat4h::get_translation_mat(Vec3h::new(1.0, 2.0, 3.0)) * Mat4h::get_uniform_scale_mat(Vec3h::new(2.0, 2.0, 2.0));
+
+        let sphere = Sphereh::new(Vec3h::new(1.0, 0.0, 0.0), 1.0).transformed(m);
+        assert_close(Vec3h::new(3.0, 2.0, 3.0), sphere.center);
+        assert_eq!(2.0, sphere.radius);
+
+        let aabb = Aabbh::new(Vec3h::zero(), Vec3h::new(1.0, 1.0, 1.0)).transformed(m);
+        assert_close(Vec3h::new(1.0, 2.0, 3.0), aabb.min);
+        assert_close(Vec3h::new(3.0, 4.0, 5.0), aabb.max);
+
+        let plane = Planeh::new(Vec3h::new(0.0, 1.0, 0.0), 1.0).transformed(m);
+        assert!(plane.signed_distance(Vec3h::new(7.0, 4.0, -2.0)).abs() < 1e-9);
+
+        let rotation = Mat4h::get_angle_axis_mat_deg(Vec3h::up(), 45.0);
+        let obb = Obbh::from_aabb(&Aabbh::new(Vec3h::zero(), Vec3h::new(2.0, 2.0, 2.0))).transformed(m * rotation);
+        assert_close(Vec3h::new(2.0, 2.0, 2.0), obb.half_extents);
+        let rotated = Aabbh::new(Vec3h::new(-1.0, -1.0, -1.0), Vec3h::new(1.0, 1.0, 1.0)).transformed(rotation);
+        assert_close(Vec3h::new(2.0f64.sqrt(), 1.0, 2.0f64.sqrt()), rotated.max);
+    }
+}
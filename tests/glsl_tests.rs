@@ -0,0 +1,65 @@
+#[cfg(test)]
+pub mod glsl_tests {
+    use straal::*;
+    use straal::glsl;
+
+    #[test]
+    fn common_functions_scalar() {
+        assert_eq!(-1.0, glsl::sign(-3.5f32));
+        assert_eq!(0.0, glsl::sign(0.0f32));
+        assert_eq!(0.25, glsl::fract(-1.75f64));
+        assert_eq!(1.5, glsl::modulo(-2.5f64, 4.0));
+        assert_eq!(-1.5, glsl::modulo(2.5f64, -4.0));
+        assert_eq!(1.0, glsl::clamp(3.0f32, -1.0, 1.0));
+        assert_eq!(2.5, glsl::mix(2.0f64, 4.0, 0.25));
+        assert_eq!(0.0, glsl::step(0.5f32, 0.49));
+        assert_eq!(1.0, glsl::step(0.5f32, 0.5));
+        assert_eq!(0.5, glsl::smoothstep(1.0f64, 3.0, 2.0));
+        assert_eq!(0.15625, glsl::smoothstep(0.0f64, 1.0, 0.25));
+    }
+
+    #[test]
+    fn common_functions_vector() {
+        let v = Vec3n::new(-1.25, 0.0, 2.75);
+        assert_eq!(Vec3n::new(1.25, 0.0, 2.75), glsl::abs(v));
+        assert_eq!(Vec3n::new(-1.0, 0.0, 1.0), glsl::sign(v));
+        assert_eq!(Vec3n::new(-2.0, 0.0, 2.0), glsl::floor(v));
+        assert_eq!(Vec3n::new(-1.0, 0.0, 3.0), glsl::ceil(v));
+        assert_eq!(Vec3n::new(0.75, 0.0, 0.75), glsl::fract(v));
+        assert_eq!(Vec3n::new(0.75, 0.0, 0.75), glsl::modulo(v, 2.0));
+        assert_eq!(Vec3n::new(-1.0, 0.0, 1.0), glsl::clamp(v, -1.0, 1.0));
+        assert_eq!(Vec3n::new(-1.25, 0.0, 0.0), glsl::min(v, Vec3n::zero()));
+        assert_eq!(Vec3n::new(0.0, 0.0, 2.75), glsl::max(v, 0.0));
+
+        let a = Vec4h::new(0.0, 1.0, 2.0, 3.0);
+        let b = Vec4h::new(4.0, 5.0, 6.0, 7.0);
+        assert_eq!(Vec4h::new(2.0, 3.0, 4.0, 5.0), glsl::mix(a, b, 0.5));
+        assert_eq!(Vec4h::new(0.0, 2.0, 2.0, 7.0), glsl::mix(a, b, Vec4h::new(0.0, 0.25, 0.0, 1.0)));
+        assert_eq!(Vec4h::new(0.0, 0.0, 1.0, 1.0), glsl::step(2.0, a));
+        assert_eq!(Vec2n::new(0.0, 1.0), glsl::smoothstep(Vec2n::new(1.0, 1.0), Vec2n::new(2.0, 2.0), Vec2n::new(0.5, 2.5)));
+    }
+
+    #[test]
+    fn geometric_functions() {
+        assert_eq!(5.0, glsl::length(Vec2h::new(3.0, 4.0)));
+        assert_eq!(3.0, glsl::length(-3.0f64));
+        assert_eq!(5.0, glsl::distance(Vec3h::new(1.0, 1.0, 1.0), Vec3h::new(4.0, 5.0, 1.0)));
+        assert_eq!(Vec3h::new(0.6, 0.8, 0.0), glsl::normalize(Vec3h::new(3.0, 4.0, 0.0)));
+
+        let n = Vec3h::up();
+        let i = Vec3h::new(1.0, -1.0, 0.0).normalized();
+        assert_eq!(Vec3h::new(1.0, 1.0, 0.0).normalized(), glsl::reflect(i, n));
+        assert_eq!(n, glsl::faceforward(n, i, n));
+        assert_eq!(-n, glsl::faceforward(n, -i, n));
+
+        //No bending with a ratio of 1, and total internal reflection at a shallow angle going into a less dense medium
+        assert_eq!(i, glsl::refract(i, n, 1.0));
+        let shallow = Vec3h::new(1.0, -0.1, 0.0).normalized();
+        assert_eq!(Vec3h::zero(), glsl::refract(shallow, n, 1.5));
+
+        //Snell's law, sin(theta_t) = eta * sin(theta_i)
+        let t = glsl::refract(i, n, 1.0 / 1.33);
+        assert!(t.x.approx_eq(i.x / 1.33, 1e-12));
+        assert!(t.is_unit());
+    }
+}
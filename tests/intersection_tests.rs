@@ -0,0 +1,113 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod intersection_tests {
+    use straal::*;
+    use straal::geometry::*;
+
+    use crate::test_helpers::*;
+
+    #[test]
+    fn ray_plane_and_sphere() {
+        let ray = Rayh::new(Vec3h::new(0.0, 5.0, 0.0), Vec3h::new(0.0, -1.0, 0.0));
+        let hit = ray.intersect_plane(&Planeh::new(Vec3h::new(0.0, 1.0, 0.0), 1.0)).unwrap();
+        assert_eq!(4.0, hit.t);
+        assert_close(Vec3h::new(0.0, 1.0, 0.0), hit.point);
+        assert_close(Vec3h::new(0.0, 1.0, 0.0), hit.normal);
+        let from_below = ray.intersect_plane(&Planeh::new(Vec3h::new(0.0, -1.0, 0.0), -1.0)).unwrap();
+        assert_close(Vec3h::new(0.0, 1.0, 0.0), from_below.normal);
+        assert!(ray.intersect_plane(&Planeh::new(Vec3h::new(0.0, 1.0, 0.0), 6.0)).is_none());
+        assert!(ray.intersect_plane(&Planeh::new(Vec3h::new(1.0, 0.0, 0.0), 1.0)).is_none());
+
+        let sphere = Sphereh::new(Vec3h::new(0.0, 0.0, 0.0), 2.0);
+        let hit = ray.intersect_sphere(&sphere).unwrap();
+        assert_eq!(3.0, hit.t);
+        assert_close(Vec3h::new(0.0, 2.0, 0.0), hit.point);
+        assert_close(Vec3h::new(0.0, 1.0, 0.0), hit.normal);
+        let inside = Rayh::new(Vec3h::zero(), Vec3h::new(1.0, 0.0, 0.0)).intersect_sphere(&sphere).unwrap();
+        assert_eq!(0.0, inside.t);
+        assert!(Rayh::new(Vec3h::new(3.0, 5.0, 0.0), Vec3h::new(0.0, -1.0, 0.0)).intersect_sphere(&sphere).is_none());
+        assert!(Rayh::new(Vec3h::new(0.0, 5.0, 0.0), Vec3h::new(0.0, 1.0, 0.0)).intersect_sphere(&sphere).is_none());
+    }
+
+    #[test]
+    fn ray_boxes_and_triangle() {
+        let aabb = Aabbh::new(Vec3h::new(-1.0, -1.0, -1.0), Vec3h::new(1.0, 1.0, 1.0));
+        let ray = Rayh::new(Vec3h::new(-5.0, 0.5, 0.0), Vec3h::new(1.0, 0.0, 0.0));
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+        assert_eq!(4.0, hit.t);
+        assert_close(Vec3h::new(-1.0, 0.5, 0.0), hit.point);
+        assert_close(Vec3h::new(-1.0, 0.0, 0.0), hit.normal);
+        assert!(Rayh::new(Vec3h::new(-5.0, 1.5, 0.0), Vec3h::new(1.0, 0.0, 0.0)).intersect_aabb(&aabb).is_none());
+        assert!(Rayh::new(Vec3h::new(-5.0, 0.5, 0.0), Vec3h::new(-1.0, 0.0, 0.0)).intersect_aabb(&aabb).is_none());
+
+        let rotation = Quath::get_quat_from_angle_axis(std::f64::consts::FRAC_PI_4, Vec3h::up());
+        let obb = Obbh::from_rotation(Vec3h::zero(), rotation, Vec3h::new(1.0, 1.0, 1.0));
+        let hit = ray.intersect_obb(&obb).unwrap();
+        assert!((hit.t - (5.0 - 2.0f64.sqrt())).abs() < 1e-9);
+        assert!(hit.normal.x < 0.0 && hit.normal.dot(ray.direction) < 0.0);
+        assert!((hit.normal.length() - 1.0).abs() < 1e-9);
+
+        let triangle = Triangleh::new(Vec3h::new(0.0, 0.0, 0.0), Vec3h::new(1.0, 0.0, 0.0), Vec3h::new(0.0, 1.0, 0.0));
+        let hit = Rayh::new(Vec3h::new(0.25, 0.25, 3.0), Vec3h::new(0.0, 0.0, -1.0)).intersect_triangle(&triangle).unwrap();
+        assert_eq!(3.0, hit.t);
+        assert_close(Vec3h::new(0.25, 0.25, 0.0), hit.point);
+        assert_close(Vec3h::new(0.0, 0.0, 1.0), hit.normal);
+        let back = Rayh::new(Vec3h::new(0.25, 0.25, -3.0), Vec3h::new(0.0, 0.0, 1.0)).intersect_triangle(&triangle).unwrap();
+        assert_close(Vec3h::new(0.0, 0.0, -1.0), back.normal);
+        assert!(Rayh::new(Vec3h::new(0.75, 0.75, 3.0), Vec3h::new(0.0, 0.0, -1.0)).intersect_triangle(&triangle).is_none());
+    }
+
+    #[test]
+    fn sphere_and_aabb_overlaps() {
+        let sphere = Sphereh::new(Vec3h::zero(), 1.0);
+        assert!(sphere.overlaps_sphere(&Sphereh::new(Vec3h::new(1.5, 0.0, 0.0), 0.6)));
+        assert!(!sphere.overlaps_sphere(&Sphereh::new(Vec3h::new(1.5, 0.0, 0.0), 0.4)));
+
+        let aabb = Aabbh::new(Vec3h::new(1.0, 1.0, 1.0), Vec3h::new(2.0, 2.0, 2.0));
+        assert!(!sphere.overlaps_aabb(&aabb));
+        assert!(Sphereh::new(Vec3h::zero(), 1.8).overlaps_aabb(&aabb));
+        assert!(aabb.overlaps_aabb(&Aabbh::new(Vec3h::new(1.5, 1.5, 1.5), Vec3h::new(3.0, 3.0, 3.0))));
+        assert!(!aabb.overlaps_aabb(&Aabbh::new(Vec3h::new(2.5, 1.5, 1.5), Vec3h::new(3.0, 3.0, 3.0))));
+    }
+
+    #[test]
+    fn obb_overlaps() {
+        let rotation = Quath::get_quat_from_angle_axis(std::f64::consts::FRAC_PI_4, Vec3h::forward());
+        let a = Obbh::from_rotation(Vec3h::zero(), rotation, Vec3h::new(1.0, 1.0, 1.0));
+        let b = Obbh::from_aabb(&Aabbh::new(Vec3h::new(1.3, -0.5, -0.5), Vec3h::new(2.3, 0.5, 0.5)));
+        let c = Obbh::from_aabb(&Aabbh::new(Vec3h::new(1.5, -0.5, -0.5), Vec3h::new(2.5, 0.5, 0.5)));
+        assert!(a.overlaps_obb(&b));
+        assert!(b.overlaps_obb(&a));
+        assert!(!a.overlaps_obb(&c));
+        assert!(!c.overlaps_obb(&a));
+        //The corner at (sqrt(2), 0, 0) pokes past the face at 1.3, but not into a box that sits above it
+        assert!(!a.overlaps_aabb(&Aabbh::new(Vec3h::new(1.0, 1.0, -0.5), Vec3h::new(2.0, 2.0, 0.5))));
+        assert!(a.overlaps_sphere(&Sphereh::new(Vec3h::new(2.0, 0.0, 0.0), 0.6)));
+        assert!(!a.overlaps_sphere(&Sphereh::new(Vec3h::new(2.0, 0.0, 0.0), 0.5)));
+    }
+
+    #[test]
+    fn frustum_overlaps() {
+        let planes = [
+            Planeh::new(Vec3h::new(1.0, 0.0, 1.0), 0.0),
+            Planeh::new(Vec3h::new(-1.0, 0.0, 1.0), 0.0),
+            Planeh::new(Vec3h::new(0.0, 1.0, 1.0), 0.0),
+            Planeh::new(Vec3h::new(0.0, -1.0, 1.0), 0.0),
+            Planeh::new(Vec3h::new(0.0, 0.0, 1.0), 1.0),
+            Planeh::new(Vec3h::new(0.0, 0.0, -1.0), -10.0),
+        ];
+        let frustum = Frustumh::from_planes(planes);
+        assert!(frustum.overlaps_sphere(&Sphereh::new(Vec3h::new(0.0, 0.0, 5.0), 1.0)));
+        assert!(frustum.overlaps_sphere(&Sphereh::new(Vec3h::new(6.0, 0.0, 5.0), 1.0)));
+        assert!(!frustum.overlaps_sphere(&Sphereh::new(Vec3h::new(0.0, 0.0, 12.0), 1.0)));
+        //Within 1.5 units of both the left and the bottom plane, but not of the frustum itself
+        assert!(!frustum.overlaps_sphere(&Sphereh::new(Vec3h::new(-4.0, -4.0, 2.0), 1.5)));
+
+        assert!(frustum.overlaps_aabb(&Aabbh::new(Vec3h::new(-1.0, -1.0, 4.0), Vec3h::new(1.0, 1.0, 6.0))));
+        assert!(!frustum.overlaps_aabb(&Aabbh::new(Vec3h::new(-1.0, -1.0, -3.0), Vec3h::new(1.0, 1.0, 0.5))));
+        assert!(!frustum.overlaps_aabb(&Aabbh::new(Vec3h::new(-10.0, -10.0, 11.0), Vec3h::new(10.0, 10.0, 12.0))));
+        let obb = Obbh::from_rotation(Vec3h::new(0.0, 0.0, 12.0), Quath::identity(), Vec3h::new(3.0, 3.0, 2.5));
+        assert!(frustum.overlaps_obb(&obb));
+    }
+}
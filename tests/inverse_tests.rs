@@ -0,0 +1,83 @@
+#[cfg(test)]
+pub mod inverse_tests {
+    use straal::*;
+
+    #[test]
+    fn try_inverse_regular_matrices() {
+        let m2 = Mat2h::new(4.0, 7.0, 2.0, 6.0);
+        assert_eq!(m2.inverse(), m2.try_inverse().unwrap());
+        assert_eq!(Mat2h::identity(), m2 * m2.try_inverse().unwrap());
+
+        let m3 = Mat3h::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+        assert_eq!(Mat3h::identity(), m3 * m3.try_inverse().unwrap());
+
+        let mut m4 = Mat4h::get_translation_mat(Vec3h::new(1.0, 2.0, 3.0));
+        m4.rotate_around_axis_deg(Vec3h::up(), 30.0);
+        m4.scale_uniformly(Vec3h::new(1e-3, 2e-3, 5e-4));
+        assert_eq!(Mat4h::identity(), m4 * m4.try_inverse().unwrap());
+
+        let q = Quath::new(1.0, 2.0, -3.0, 0.5);
+        assert_eq!(Quath::identity(), q * q.try_inverse().unwrap());
+        assert_eq!(Quath::identity(), q * q.inverse());
+        assert_eq!(Quath::identity(), q / q);
+    }
+
+    #[test]
+    fn try_inverse_singular_matrices() {
+        assert!(Mat2h::new(1.0, 2.0, 2.0, 4.0).try_inverse().is_none());
+        assert!(Mat3h::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).try_inverse().is_none());
+        assert!(Mat4h::empty().try_inverse().is_none());
+        assert!(Mat4h::get_uniform_scale_mat(Vec3h::new(1.0, 1.0, 0.0)).try_inverse().is_none());
+        assert!(Quath::new(0.0, 0.0, 0.0, 0.0).try_inverse().is_none());
+
+        //Nearly singular matrices only pass with a small enough epsilon
+        let nearly = Mat2n::new(1.0, 1.0, 1.0, 1.0001);
+        assert!(nearly.try_inverse().is_some());
+        assert!(nearly.try_inverse_with_epsilon(1e-3).is_none());
+        assert!(Quatn::new(1e-4, 0.0, 0.0, 0.0).try_inverse_with_epsilon(1e-3).is_none());
+    }
+
+    #[test]
+    fn inverse_affine() {
+        let mut m = Mat4h::get_translation_mat(Vec3h::new(-4.0, 2.0, 9.0));
+        m.rotate_around_axis_deg(Vec3h::new(1.0, 1.0, 1.0).normalized(), 75.0);
+        m.scale_uniformly(Vec3h::new(2.0, 3.0, 0.5));
+        assert_eq!(m.inverse(), m.inverse_affine());
+        assert_eq!(m.inverse(), m.try_inverse_affine().unwrap());
+        assert_eq!(Mat4h::identity(), m * m.inverse_affine());
+
+        assert!(Mat4h::get_uniform_scale_mat(Vec3h::new(0.0, 1.0, 1.0)).try_inverse_affine().is_none());
+        let projection = Mat4h::get_perspective_mat_deg(60.0, 1.0, 0.1, 100.0, Handedness::Left, DepthRange::ZeroToOne);
+        assert!(!projection.is_affine());
+        assert!(projection.try_inverse_affine().is_none());
+    }
+
+    #[test]
+    fn inverse_rigid_and_orthonormal() {
+        let rotation = Mat3h::get_angle_axis_mat_deg(Vec3h::new(1.0, -2.0, 0.5).normalized(), 130.0);
+        assert_eq!(rotation.inverse(), rotation.inverse_orthonormal());
+        assert_eq!(Mat3h::identity(), rotation * rotation.inverse_orthonormal());
+
+        let mut m = Mat4h::get_translation_mat(Vec3h::new(3.0, -1.0, 7.0));
+        m *= Mat4h::from(rotation);
+        assert_eq!(m.inverse(), m.inverse_rigid());
+        assert_eq!(m.inverse_affine(), m.inverse_rigid());
+        let p = Vec4h::new(1.0, 2.0, 3.0, 1.0);
+        assert_eq!(p, m.inverse_rigid() * (m * p));
+    }
+
+    #[test]
+    #[should_panic(expected = "singular Mat3")]
+    fn not_on_singular_matrix() {
+        let _ = !Mat3h::empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "singular Mat4")]
+    fn div_by_nearly_singular_matrix() {
+        //The first 2 rows are all but parallel, the determinant is 1e-20
+        let m = Mat4h::new_from_vec4s(Vec4h::new(1.0, 0.0, 0.0, 0.0), Vec4h::new(1.0, 1e-20, 0.0, 0.0),
+                                      Vec4h::new(0.0, 0.0, 1.0, 0.0), Vec4h::new(0.0, 0.0, 0.0, 1.0));
+        let _ = Mat4h::identity() / m;
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+pub mod layout_tests {
+    #[cfg(feature = "glium")]
+    use glium::uniforms::{AsUniformValue, UniformValue};
+
+    use straal::*;
+    #[cfg(feature = "glium")]
+    use straal::column_major::ColumnMajor;
+
+    #[test]
+    fn column_major_accessors() {
+        let m = Mat4n::new(0.0, 1.0, 2.0, 3.0,
+                           4.0, 5.0, 6.0, 7.0,
+                           8.0, 9.0, 10.0, 11.0,
+                           12.0, 13.0, 14.0, 15.0);
+        let cm = m.to_column_major();
+        assert_eq!([0.0, 4.0, 8.0, 12.0, 1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0], cm.as_slice());
+        assert_eq!([3.0, 7.0, 11.0, 15.0], cm.as_array()[3]);
+        assert_eq!(Vec4n::new(1.0, 5.0, 9.0, 13.0), cm.get_column(1));
+        assert_eq!(m, cm.to_row_major());
+        assert_eq!(m, Mat4n::from(cm));
+
+        let m2 = Mat2h::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!([1.0, 3.0, 2.0, 4.0], m2.to_column_major().as_slice());
+        let m3 = Mat3h::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!([[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]], *m3.to_column_major().as_array());
+    }
+
+    #[cfg(feature = "glium")]
+    #[test]
+    fn column_major_uniforms_match_row_major_uniforms() {
+        let m = Mat4n::get_translation_mat(Vec3n::new(1.0, 2.0, 3.0)) * Mat4n::get_angle_axis_mat_deg(Vec3n::up(), 30.0);
+        match (m.as_uniform_value(), m.to_column_major().as_uniform_value()) {
+            (UniformValue::Mat4(a), UniformValue::Mat4(b)) => {
+                assert_eq!(a, b);
+                assert_eq!([1.0, 2.0, 3.0, 1.0], b[3]);
+            }
+            _ => panic!("Expected Mat4 uniforms")
+        }
+
+        let m = Mat3h::get_angle_axis_mat_deg(Vec3h::up(), 30.0);
+        match (m.as_uniform_value(), ColumnMajor::from(m).as_uniform_value()) {
+            (UniformValue::DoubleMat3(a), UniformValue::DoubleMat3(b)) => assert_eq!(a, b),
+            _ => panic!("Expected DoubleMat3 uniforms")
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+pub mod non_square_tests {
+    use straal::column_major::ColumnMajor;
+    use straal::*;
+
+    #[test]
+    fn non_square_products() {
+        //2 columns, 3 rows, takes a Vec2 to a Vec3
+        let a = Mat2x3h::new_from_rows(Vec2h::new(1.0, 2.0), Vec2h::new(3.0, 4.0), Vec2h::new(5.0, 6.0));
+        assert_eq!(Vec3h::new(5.0, 11.0, 17.0), a * Vec2h::new(1.0, 2.0));
+
+        let b = a.transpose();
+        assert_eq!(Vec3h::new(1.0, 3.0, 5.0), b.r0);
+        assert_eq!(a, b.transpose());
+
+        //Mat2x3 * Mat3x2 is a Mat3 and the other way around a Mat2
+        let outer: Mat3h = a * b;
+        let inner: Mat2h = b * a;
+        assert_eq!(Mat3h::new(5.0, 11.0, 17.0, 11.0, 25.0, 39.0, 17.0, 39.0, 61.0), outer);
+        assert_eq!(Mat2h::new(35.0, 44.0, 44.0, 56.0), inner);
+        assert_eq!((a * b) * Vec3h::new(1.0, -1.0, 2.0), a * (b * Vec3h::new(1.0, -1.0, 2.0)));
+
+        assert_eq!(Vec2h::new(1.0, 0.0), Mat3x2h::identity().r0.xy());
+        assert_eq!(a, (a * 2.0) / 2.0);
+        assert_eq!(Vec2h::new(10.0, 12.0), (a * 2.0).r2);
+    }
+
+    #[test]
+    fn affine_conversions() {
+        let m = Mat4h::get_translation_mat(Vec3h::new(1.0, 2.0, 3.0)) * Mat4h::get_rotation_mat_euler_obj_upr_rad(0.3, -0.2, 0.9);
+        let affine = Mat4x3h::from(m);
+        let p = Vec4h::new(4.0, -5.0, 6.0, 1.0);
+        assert_eq!((m * p).xyz(), affine * p);
+        assert_eq!(m, Mat4h::from(affine));
+
+        //Chaining an affine Mat4x3 with a full Mat4 stays affine
+        assert_eq!(Mat4x3h::from(m * m), affine * m);
+
+        let m2 = Mat3h::new(0.0, -1.0, 2.0, 1.0, 0.0, 3.0, 0.0, 0.0, 1.0);
+        let affine2 = Mat3x2h::from(m2);
+        assert_eq!(Vec2h::new(1.0, 4.0), affine2 * Vec3h::new(1.0, 1.0, 1.0));
+        assert_eq!(m2, Mat3h::from(affine2));
+    }
+
+    #[test]
+    fn non_square_column_major() {
+        let m = Mat3x2n::new_from_rows(Vec3n::new(1.0, 2.0, 3.0), Vec3n::new(4.0, 5.0, 6.0));
+        let cm = m.to_column_major();
+        assert_eq!(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0], cm.as_slice());
+        assert_eq!(&[[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]], cm.as_array());
+        assert_eq!(Vec2n::new(3.0, 6.0), cm.get_column(2));
+        assert_eq!(m, Mat3x2n::from(ColumnMajor::from(m)));
+        assert_eq!(std::mem::size_of::<Mat3x2n>(), std::mem::size_of::<ColumnMajor<Mat3x2n>>());
+    }
+}
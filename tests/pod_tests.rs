@@ -0,0 +1,33 @@
+#[cfg(all(test, feature = "bytemuck"))]
+pub mod pod_tests {
+    use straal::*;
+
+    #[test]
+    fn as_bytes_matches_the_fields() {
+        let v = Vec3n::new(1.0, 2.0, 3.0);
+        let mut expected = Vec::new();
+        for f in [1.0f32, 2.0, 3.0].iter() {
+            expected.extend_from_slice(&f.to_ne_bytes());
+        }
+        assert_eq!(expected.as_slice(), v.as_bytes());
+
+        assert_eq!(64, Mat4n::identity().as_bytes().len());
+        assert_eq!(128, Mat4h::identity().as_bytes().len());
+        assert_eq!(48, Mat3x4n::identity().as_bytes().len());
+        assert_eq!(32, DualQuatn::identity().as_bytes().len());
+        assert_eq!(12, IVec3n::new(1, 2, 3).as_bytes().len());
+        assert_eq!(&1.0f32.to_ne_bytes(), &Quatn::identity().as_bytes()[0..4]);
+    }
+
+    #[test]
+    fn slices_cast_to_bytes_and_back() {
+        let vertices = vec![Vec3n::new(1.0, 2.0, 3.0), Vec3n::new(4.0, 5.0, 6.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+        assert_eq!(24, bytes.len());
+        let floats: &[f32] = bytemuck::cast_slice(&vertices);
+        assert_eq!([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], floats);
+
+        let zeroed: Mat2n = bytemuck::Zeroable::zeroed();
+        assert_eq!(Mat2n::new(0.0, 0.0, 0.0, 0.0), zeroed);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+pub mod projection_tests {
+    use straal::*;
+
+    fn project(m: Mat4h, p: Vec3h) -> Vec3h {
+        let clip = m * Vec4h::from((p, 1.0));
+        Vec3h::from(clip) / clip.w
+    }
+
+    #[test]
+    fn perspective_depth_ranges() {
+        let fov = std::f64::consts::FRAC_PI_2;
+        let gl = Mat4h::get_perspective_mat_rad(fov, 1.0, 0.1, 100.0, Handedness::Right, DepthRange::NegOneToOne);
+        assert!(project(gl, Vec3h::new(0.0, 0.0, -0.1)).z.approx_eq(-1.0, 1e-9));
+        assert!(project(gl, Vec3h::new(0.0, 0.0, -100.0)).z.approx_eq(1.0, 1e-9));
+
+        let vk = Mat4h::get_perspective_mat_rad(fov, 1.0, 0.1, 100.0, Handedness::Left, DepthRange::ZeroToOne);
+        assert!(project(vk, Vec3h::new(0.0, 0.0, 0.1)).z.approx_eq(0.0, 1e-9));
+        assert!(project(vk, Vec3h::new(0.0, 0.0, 100.0)).z.approx_eq(1.0, 1e-9));
+
+        //With a 90 degree fov the edges of the frustum sit at x == depth
+        assert_eq!(Vec3h::new(1.0, 1.0, 0.0), project(vk, Vec3h::new(5.0, 5.0, 5.0)) * Vec3h::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn perspective_matches_frustum() {
+        let fov = std::f64::consts::FRAC_PI_3;
+        let top = 0.5 * (fov / 2.0).tan();
+        let right = top * 1.5;
+        let persp = Mat4h::get_perspective_mat_rad(fov, 1.5, 0.5, 50.0, Handedness::Right, DepthRange::NegOneToOne);
+        let frustum = Mat4h::get_frustum_mat(-right, right, -top, top, 0.5, 50.0, Handedness::Right, DepthRange::NegOneToOne);
+        assert_eq!(persp, frustum);
+    }
+
+    #[test]
+    fn reversed_z() {
+        let fov = std::f64::consts::FRAC_PI_2;
+        let m = Mat4h::get_reversed_z_perspective_mat_rad(fov, 1.0, 1.0, 10.0, Handedness::Left, DepthRange::ZeroToOne);
+        assert!(project(m, Vec3h::new(0.0, 0.0, 1.0)).z.approx_eq(1.0, 1e-9));
+        assert!(project(m, Vec3h::new(0.0, 0.0, 10.0)).z.approx_eq(0.0, 1e-9));
+
+        let inf = Mat4h::get_infinite_reversed_z_perspective_mat_rad(fov, 1.0, 1.0, Handedness::Left, DepthRange::ZeroToOne);
+        assert!(project(inf, Vec3h::new(0.0, 0.0, 1.0)).z.approx_eq(1.0, 1e-9));
+        assert!(project(inf, Vec3h::new(0.0, 0.0, 1e12)).z.abs() < 1e-9);
+    }
+
+    #[test]
+    fn infinite_perspective() {
+        let fov = std::f64::consts::FRAC_PI_2;
+        let m = Mat4h::get_infinite_perspective_mat_rad(fov, 1.0, 0.1, Handedness::Right, DepthRange::NegOneToOne);
+        assert!(project(m, Vec3h::new(0.0, 0.0, -0.1)).z.approx_eq(-1.0, 1e-9));
+        assert!(project(m, Vec3h::new(0.0, 0.0, -1e12)).z < 1.0);
+    }
+
+    #[test]
+    fn orthographic() {
+        let m = Mat4h::get_orthographic_mat(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0, Handedness::Right, DepthRange::NegOneToOne);
+        assert_eq!(Vec3h::new(1.0, -1.0, -1.0), project(m, Vec3h::new(2.0, -1.0, -1.0)));
+        assert_eq!(Vec3h::new(0.0, 0.0, 1.0), project(m, Vec3h::new(0.0, 0.0, -11.0)));
+
+        let m = Mat4h::get_orthographic_mat(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0, Handedness::Left, DepthRange::ZeroToOne);
+        assert_eq!(Vec3h::new(-1.0, 1.0, 0.5), project(m, Vec3h::new(-2.0, 1.0, 6.0)));
+    }
+}
@@ -0,0 +1,93 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod quat_calculus_tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    #[test]
+    fn exp_and_ln() {
+        let q = Quath::get_quat_from_angle_axis(1.3, Vec3h::new(1.0, -2.0, 2.0).normalized());
+        assert_close_eps(q, q.ln().exp(), 1e-12);
+        assert_close_eps(Quath::new(0.0, 0.65 / 3.0, -1.3 / 3.0, 1.3 / 3.0), q.ln(), 1e-12);
+
+        let scaled = q * 3.0;
+        assert_close_eps(scaled, scaled.ln().exp(), 1e-12);
+        assert!((scaled.ln().w - 3.0f64.ln()).abs() < 1e-12);
+        assert_close_eps(q.pow(0.3), (q.ln() * 0.3).exp(), 1e-12);
+
+        //Close to the identity, where sin(x) / x and atan(x) / x lose their precision
+        let tiny = Quath::new(0.0, 1e-9, -2e-9, 0.5e-9);
+        assert_close_eps(Quath::new(1.0, 1e-9, -2e-9, 0.5e-9), tiny.exp(), 1e-17);
+        assert_close_eps(tiny, tiny.exp().ln(), 1e-20);
+        assert_eq!(Quath::new(0.0, 0.0, 0.0, 0.0), Quath::identity().ln());
+        assert_close_eps(-Quath::identity(), Quath::new(0.0, std::f64::consts::PI, 0.0, 0.0).exp(), 1e-12);
+        assert_close_eps(-Quath::identity(), (-Quath::identity()).ln().exp(), 1e-12);
+
+        //Small but not unit, the angle is still almost a quarter turn
+        let small = Quath::new(1e-8, 1e-5, 0.0, 0.0);
+        assert!((small.ln().v.x - 1e-5f64.atan2(1e-8)).abs() < 1e-12);
+        assert!((small.ln().v.x - 1.5698).abs() < 1e-4);
+        assert_close_eps(small, small.ln().exp(), 1e-17);
+    }
+
+    #[test]
+    fn angular_velocity() {
+        let omega = Vec3h::new(0.0, 2.0, 0.0);
+        let q = Quath::from_angular_velocity(omega, 0.25);
+        assert_close_eps(Quath::get_quat_from_angle_axis(0.5, Vec3h::up()), q, 1e-12);
+        assert!((omega - Quath::angular_velocity_between(Quath::identity(), q, 0.25)).length() < 1e-12);
+
+        //The shortest way around, even if q1 is on the other hemisphere
+        let q0 = Quath::get_quat_from_angle_axis(0.4, Vec3h::right());
+        let q1 = Quath::get_quat_from_angle_axis(-0.2, Vec3h::forward()) * q0;
+        let between = Quath::angular_velocity_between(q0, -q1, 0.5);
+        assert!((Vec3h::new(0.0, 0.0, -0.4) - between).length() < 1e-12);
+        assert_close_eps(q1, Quath::from_angular_velocity(between, 0.5) * q0, 1e-12);
+
+        //Integrating a constant angular velocity in small steps stays a unit quaternion and ends up in the right place
+        let mut integrated = q0;
+        for _ in 0..1000 {
+            integrated.integrate_angular_velocity(Vec3h::new(0.0, FRAC_PI_2, 0.0), 0.001);
+        }
+        assert!(integrated.is_unit());
+        assert_close_eps(Quath::get_quat_from_angle_axis(FRAC_PI_2, Vec3h::up()) * q0, integrated, 1e-9);
+        assert!(Quath::angular_velocity_between(q0, q0, 0.1).length() < 1e-15);
+    }
+
+    #[test]
+    fn squad_paths() {
+        let keys = [Quath::identity(),
+            Quath::get_quat_from_angle_axis(0.8, Vec3h::up()),
+            -Quath::get_quat_from_angle_axis(1.1, Vec3h::new(1.0, 1.0, 0.0).normalized()),
+            Quath::get_quat_from_angle_axis(0.3, Vec3h::right())];
+        let controls: Vec<Quath> = (0..keys.len())
+            .map(|i| keys[i].get_squad_control(keys[i.saturating_sub(1)], keys[(i + 1).min(keys.len() - 1)]))
+            .collect();
+
+        let get_segment = |i: usize, t: f64| keys[i].squad(keys[i + 1], controls[i], controls[i + 1], t);
+
+        //Goes through the keys, with a continuous tangent where the segments meet
+        for i in 0..keys.len() - 1 {
+            let start = get_segment(i, 0.0);
+            let end = get_segment(i, 1.0);
+            assert!(start.dot(keys[i]).abs() > 1.0 - 1e-12);
+            assert!(end.dot(keys[i + 1]).abs() > 1.0 - 1e-12);
+            assert!(get_segment(i, 0.37).is_unit());
+        }
+        let h = 1e-5;
+        for i in 1..keys.len() - 1 {
+            let before = Quath::angular_velocity_between(get_segment(i - 1, 1.0 - h), get_segment(i - 1, 1.0), h);
+            let after = Quath::angular_velocity_between(get_segment(i, 0.0), get_segment(i, h), h);
+            assert!((before - after).length() < 1e-3, "{} vs {}", before, after);
+        }
+
+        //With the controls at the keys, squad is slerp
+        let a = keys[0];
+        let b = keys[1];
+        assert_close_eps(a.slerp(b, 0.3), a.squad(b, a, b, 0.3), 1e-12);
+    }
+}
@@ -0,0 +1,91 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod quat_construction_tests {
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    fn assert_same_rotation(expected: Quath, actual: Quath) {
+        assert!(expected.dot(actual).abs() > 1.0 - 1e-12, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn rotation_arcs() {
+        let from = Vec3h::new(1.0, 2.0, -1.0);
+        let to = Vec3h::new(-3.0, 0.5, 2.0);
+        let q = Quath::from_rotation_arc(from, to);
+        assert!(q.is_unit());
+        assert_close(to.normalized(), q * from.normalized());
+        //The shortest arc does not spin around the from and to directions
+        assert_close(from.cross(to).normalized(), q * from.cross(to).normalized());
+
+        assert_eq!(Quath::identity(), Quath::from_rotation_arc(from, from * 2.0));
+        for dir in [Vec3h::right(), Vec3h::up(), from].iter() {
+            let half_turn = Quath::from_rotation_arc(*dir, -*dir);
+            assert!(half_turn.is_unit());
+            assert_close(-dir.normalized(), half_turn * dir.normalized());
+        }
+
+        //Nearly opposite directions still turn around their own cross product, not some arbitrary perpendicular axis
+        let to = Vec3h::new(-1.0, 0.01, 0.0);
+        let q = Quath::from_rotation_arc(Vec3h::right(), to);
+        assert!(q.is_unit());
+        assert_close(to.normalized(), q * Vec3h::right());
+        assert!(q.v.x.abs() < 1e-12 && q.v.y.abs() < 1e-12);
+        assert!(q.v.z > 0.0);
+    }
+
+    #[test]
+    fn look_rotations() {
+        let forward = Vec3h::new(1.0, 0.5, 1.0);
+        let q = Quath::look_rotation(forward, Vec3h::up());
+        assert_close(forward.normalized(), q * Vec3h::forward());
+        //The up vector stays in the plane of forward and the world up, on the upper side
+        let up = q * Vec3h::up();
+        assert!(up.dot(forward.cross(Vec3h::up())).abs() < 1e-12);
+        assert!(up.y > 0.0);
+        assert_close(Vec3h::up().cross(forward).normalized(), q * Vec3h::right());
+
+        assert_eq!(Quath::identity(), Quath::look_rotation(Vec3h::forward(), Vec3h::up()));
+        //Looking straight up can not keep the up vector
+        assert_close(Vec3h::up(), Quath::look_rotation(Vec3h::up(), Vec3h::up()) * Vec3h::forward());
+    }
+
+    #[test]
+    fn swing_twist() {
+        let axis = Vec3h::new(0.0, 1.0, 1.0).normalized();
+        let twist_only = Quath::get_quat_from_angle_axis(0.6, axis);
+        let swing_only = Quath::get_quat_from_angle_axis(1.1, Vec3h::new(0.0, 1.0, -1.0).normalized());
+        let (swing, twist) = (swing_only * twist_only).get_swing_twist(axis);
+        assert_same_rotation(swing_only, swing);
+        assert_same_rotation(twist_only, twist);
+        assert_eq!(swing_only * twist_only, swing * twist);
+        //The swing moves the axis without any spin around it
+        assert!(swing.v.dot(axis).abs() < 1e-12);
+
+        let (swing, twist) = Quath::get_quat_from_angle_axis(std::f64::consts::PI, Vec3h::right()).get_swing_twist(Vec3h::up());
+        assert_eq!(Quath::identity(), twist);
+        assert_close(-Vec3h::up(), swing * Vec3h::up());
+    }
+
+    #[test]
+    fn from_mat3_orthonormalizes() {
+        let q = Quath::get_quat_from_angle_axis(2.3, Vec3h::new(1.0, -2.0, 2.0).normalized());
+        let m = Mat3h::from(q);
+        assert_same_rotation(q, Quath::from_mat3(m));
+
+        //Scaled, drifted and sheared copies of the same rotation
+        let scaled = Mat3h::get_uniform_scale_mat(Vec3h::new(2.0, 0.5, 3.0)) * m;
+        assert_same_rotation(q, Quath::from_mat3(scaled));
+        let mut drifted = m;
+        drifted[0][1] += 1e-4;
+        drifted[2][2] -= 2e-4;
+        let from_drifted = Quath::from_mat3(drifted);
+        assert!(from_drifted.is_unit());
+        assert!(from_drifted.dot(q).abs() > 1.0 - 1e-7);
+
+        let f = Quatn::from_mat3(Mat3n::get_uniform_scale_mat(Vec3n::new(4.0, 4.0, 4.0)) * Mat3n::from(Quatn::get_quat_from_angle_axis(-0.4, Vec3n::up())));
+        assert!(f.dot(Quatn::get_quat_from_angle_axis(-0.4, Vec3n::up())).abs() > 1.0 - 1e-5);
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(all(test, feature = "serde"))]
+pub mod serde_tests {
+    use straal::*;
+
+    #[test]
+    fn vectors_are_arrays() {
+        let v = Vec3n::new(1.0, -2.5, 3.0);
+        assert_eq!("[1.0,-2.5,3.0]", serde_json::to_string(&v).unwrap());
+        assert_eq!(v, serde_json::from_str::<Vec3n>("[1.0, -2.5, 3.0]").unwrap());
+
+        let v = Vec4h::new(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(v, serde_json::from_str::<Vec4h>(&serde_json::to_string(&v).unwrap()).unwrap());
+        let v = Vec2n::new(7.0, 8.0);
+        assert_eq!(v, serde_json::from_str::<Vec2n>(&serde_json::to_string(&v).unwrap()).unwrap());
+
+        let iv = IVec3n::new(1, -2, 3);
+        assert_eq!("[1,-2,3]", serde_json::to_string(&iv).unwrap());
+        assert_eq!(iv, serde_json::from_str::<IVec3n>("[1,-2,3]").unwrap());
+        let iv = IVec2h::new(4, 5);
+        assert_eq!(iv, serde_json::from_str::<IVec2h>(&serde_json::to_string(&iv).unwrap()).unwrap());
+        let iv = IVec4n::new(4, 5, -6, 7);
+        assert_eq!(iv, serde_json::from_str::<IVec4n>(&serde_json::to_string(&iv).unwrap()).unwrap());
+
+        assert!(serde_json::from_str::<Vec3n>("[1.0, 2.0]").is_err());
+        assert!(serde_json::from_str::<Vec2n>("{\"x\": 1.0, \"y\": 2.0}").is_err());
+    }
+
+    #[test]
+    fn matrices_are_rows() {
+        let m = Mat2n::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!("[[1.0,2.0],[3.0,4.0]]", serde_json::to_string(&m).unwrap());
+        assert_eq!(m, serde_json::from_str::<Mat2n>("[[1.0, 2.0], [3.0, 4.0]]").unwrap());
+
+        let m = Mat3h::get_angle_axis_mat_deg(Vec3h::new(1.0, 2.0, 3.0).normalized(), 40.0);
+        assert_eq!(m, serde_json::from_str::<Mat3h>(&serde_json::to_string(&m).unwrap()).unwrap());
+
+        let m = Mat4n::get_translation_mat(Vec3n::new(1.0, 2.0, 3.0)) * Mat4n::get_angle_axis_mat_deg(Vec3n::up(), 30.0);
+        assert_eq!(m, serde_json::from_str::<Mat4n>(&serde_json::to_string(&m).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn quaternions_are_wxyz() {
+        let q = Quath::get_quat_from_angle_axis(0.7, Vec3h::new(1.0, -1.0, 0.5).normalized());
+        let json = serde_json::to_string(&q).unwrap();
+        let arr = serde_json::from_str::<[f64; 4]>(&json).unwrap();
+        assert_eq!(q, Quath::new(arr[0], arr[1], arr[2], arr[3]));
+        assert!((0.7f64 * 0.5).cos().approx_eq(arr[0], 1e-12));
+        assert_eq!(q, serde_json::from_str::<Quath>(&json).unwrap());
+
+        assert_eq!(Quatn::identity(), serde_json::from_str::<Quatn>("[1.0, 0.0, 0.0, 0.0]").unwrap());
+    }
+}
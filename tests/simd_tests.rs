@@ -0,0 +1,60 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod simd_tests {
+    use straal::*;
+    use straal::simd::scalar;
+
+    use crate::test_helpers::*;
+
+    fn get_test_mats() -> Vec<Mat4n> {
+        let mut trs = Mat4n::get_translation_mat(Vec3n::new(1.0, -2.0, 3.0));
+        trs.rotate_around_axis_deg(Vec3n::new(1.0, 2.0, 3.0).normalized(), 40.0);
+        trs.scale_uniformly(Vec3n::new(2.0, 0.5, 3.0));
+        vec![
+            Mat4n::identity(),
+            trs,
+            Mat4n::new(1.0, 2.0, 3.0, 4.0, 5.0, -6.0, 7.0, 8.0, 9.0, 10.0, 11.0, -12.0, 13.0, 14.0, -15.0, 16.0),
+            Mat4n::get_perspective_mat_rad(1.2, 16.0 / 9.0, 0.1, 100.0, Handedness::Right, DepthRange::ZeroToOne),
+        ]
+    }
+
+    #[test]
+    fn mat4_matches_scalar() {
+        let mats = get_test_mats();
+        for a in mats.iter() {
+            assert_eq!(scalar::mat4_transpose(a), a.transpose());
+            assert_close_eps(scalar::mat4_inverse(a), a.inverse(), 1e-4 * a.r0.length().max(1.0 / a.r0.length()));
+            assert_close_eps(Mat4n::identity(), *a * a.inverse(), 1e-4);
+
+            let v = Vec4n::new(0.5, -1.5, 2.0, 1.0);
+            assert_eq!(scalar::mat4_mul_vec4(a, v), *a * v);
+            for b in mats.iter() {
+                assert_close_eps(scalar::mat4_mul(a, b), *a * *b, 1e-5);
+            }
+        }
+
+        //Singular matrices give non-finite elements, just like the scalar version
+        let singular = Mat4n::new(1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0);
+        assert!(!singular.inverse().r0.x.is_finite());
+    }
+
+    #[test]
+    fn quat_matches_scalar() {
+        let a = Quatn::get_quat_from_angle_axis(0.7, Vec3n::new(1.0, -1.0, 0.5).normalized());
+        let b = Quatn::get_quat_from_angle_axis(-2.1, Vec3n::new(0.2, 3.0, 1.0).normalized());
+        let c = Quatn::new(1.0, 2.0, 3.0, 4.0);
+        for (lhs, rhs) in [(a, b), (b, a), (a, c), (c, b), (c, c)].iter() {
+            let expected = scalar::quat_mul(*lhs, *rhs);
+            let actual = *lhs * *rhs;
+            assert!((expected.w - actual.w).abs() < 1e-5 && (expected.v - actual.v).length() < 1e-5, "expected {}, got {}", expected, actual);
+        }
+    }
+
+    #[test]
+    fn f64_uses_the_scalar_path() {
+        let m = Mat4h::new(1.0, 2.0, 3.0, 4.0, 5.0, -6.0, 7.0, 8.0, 9.0, 10.0, 11.0, -12.0, 13.0, 14.0, -15.0, 16.0);
+        assert_eq!(scalar::mat4_inverse(&m), m.inverse());
+        assert_eq!(scalar::mat4_mul(&m, &m.transpose()), m * m.transpose());
+    }
+}
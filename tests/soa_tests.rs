@@ -0,0 +1,106 @@
+#[cfg(test)]
+pub mod soa_tests {
+    use straal::*;
+    use straal::soa::*;
+
+    fn get_points(count: usize) -> Vec<Vec3h> {
+        (0..count).map(|i| {
+            let f = i as f64;
+            Vec3h::new(f.sin() * 3.0, f * 0.25 - 1.0, (f * 0.7).cos() + 0.5)
+        }).collect()
+    }
+
+    fn get_mat() -> Mat4h {
+        let mut m = Mat4h::get_translation_mat(Vec3h::new(1.0, -2.0, 3.0));
+        m.rotate_around_axis_deg(Vec3h::new(1.0, 2.0, 3.0).normalized(), 40.0);
+        m.scale_uniformly(Vec3h::new(2.0, 0.5, 3.0));
+        m
+    }
+
+    #[test]
+    fn lanes_match_aos() {
+        let a = get_points(8);
+        let b = get_points(16)[8..].to_vec();
+        let m = get_mat();
+        let (la, lb) = (Vec3x8h::load(&a), Vec3x8h::load(&b));
+        let (dot, cross, normalized) = (la.dot(&lb), la.cross(&lb), la.normalized());
+        let (points, vectors) = (la.transform_points(&m), la.transform_vectors(&m));
+        for i in 0..8 {
+            assert_eq!(a[i].dot(b[i]), dot[i]);
+            assert_eq!(a[i].cross(b[i]), cross.get(i));
+            assert_eq!(a[i].normalized(), normalized.get(i));
+            assert_eq!(Vec3h::from(m * Vec4h::from((a[i], 1.0))), points.get(i));
+            assert_eq!(Vec3h::from(m * Vec4h::from((a[i], 0.0))), vectors.get(i));
+            assert_eq!(a[i] + b[i], (la + lb).get(i));
+            assert_eq!(a[i] * 2.0, (la * 2.0).get(i));
+        }
+
+        //A short slice leaves the remaining lanes at zero, and storing only writes what fits
+        let short = Vec3x4n::load(&[Vec3n::new(1.0, 2.0, 3.0)]);
+        assert_eq!(Vec3n::new(1.0, 2.0, 3.0), short.get(0));
+        assert_eq!(Vec3n::zero(), short.get(3));
+        let mut out = [Vec4n::new(0.0, 0.0, 0.0, 1.0); 2];
+        short.store_vec4(&mut out);
+        assert_eq!([Vec4n::new(1.0, 2.0, 3.0, 1.0), Vec4n::new(0.0, 0.0, 0.0, 1.0)], out);
+
+        let arr = [Vec3n::new(1.0, 0.0, 0.0), Vec3n::up(), Vec3n::forward(), Vec3n::one()];
+        assert_eq!(arr, <[Vec3n; 4]>::from(Vec3x4n::from(arr)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn soa_matches_aos() {
+        let a = get_points(37);
+        let b: Vec<Vec3h> = a.iter().map(|p| p.cross(Vec3h::up()) + Vec3h::forward()).collect();
+        let m = get_mat();
+
+        let sa = Vec3Soah::from_slice(&a);
+        let sb = Vec3Soah::from(b.as_slice());
+        assert_eq!(37, sa.len());
+        assert_eq!(a, sa.to_vec());
+
+        let dot = sa.dot(&sb);
+        let cross = sa.cross(&sb);
+        let mut normalized = sa.clone();
+        normalized.normalize();
+        let mut points = sa.clone();
+        points.transform_points(&m);
+        let mut vectors = sa.clone();
+        vectors.transform_vectors(&m);
+        for i in 0..a.len() {
+            assert_eq!(a[i].dot(b[i]), dot[i]);
+            assert_eq!(a[i].cross(b[i]), cross.get(i));
+            assert_eq!(a[i].normalized(), normalized.get(i));
+            assert_eq!(Vec3h::from(m * Vec4h::from((a[i], 1.0))), points.get(i));
+            assert_eq!(Vec3h::from(m * Vec4h::from((a[i], 0.0))), vectors.get(i));
+        }
+
+        //Back into the Vec4s that came in, w stays as it was
+        let mut homogeneous: Vec<Vec4h> = a.iter().map(|p| Vec4h::from((*p, 1.0))).collect();
+        let soa = Vec3Soah::from(homogeneous.as_slice());
+        let mut moved = soa.clone();
+        moved.transform_points(&m);
+        moved.store_vec4(&mut homogeneous);
+        for (i, h) in homogeneous.iter().enumerate() {
+            assert_eq!(m * Vec4h::from((a[i], 1.0)), *h);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn soa_slices() {
+        let mut soa = Vec3Soah::from_slice(&get_points(5));
+        let (x, y, z) = soa.as_slices();
+        assert_eq!(soa.get(3), Vec3h::new(x[3], y[3], z[3]));
+        let (x, _, _) = soa.as_mut_slices();
+        x[3] = 7.0;
+        assert_eq!(7.0, soa.get(3).x);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn soa_length_mismatch() {
+        Vec3Soah::from_slice(&get_points(5)).dot(&Vec3Soah::from_slice(&get_points(4)));
+    }
+}
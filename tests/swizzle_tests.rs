@@ -0,0 +1,49 @@
+#[cfg(test)]
+pub mod swizzle_tests {
+    use straal::*;
+
+    #[test]
+    fn float_swizzle_getters() {
+        let v = Vec4n::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Vec2n::new(1.0, 2.0), v.xy());
+        assert_eq!(Vec3n::new(3.0, 2.0, 1.0), v.zyx());
+        assert_eq!(Vec4n::new(1.0, 1.0, 2.0, 2.0), v.xxyy());
+        assert_eq!(v, v.rgba());
+        assert_eq!(Vec4n::new(4.0, 3.0, 2.0, 1.0), v.abgr());
+
+        let v = Vec2h::new(5.0, 6.0);
+        assert_eq!(Vec3h::new(6.0, 5.0, 6.0), v.yxy());
+        assert_eq!(Vec4h::new(5.0, 5.0, 5.0, 6.0), v.rrrg());
+
+        let v = Vec3n::new(7.0, 8.0, 9.0);
+        assert_eq!(Vec2n::new(9.0, 7.0), v.zx());
+        assert_eq!(Vec4n::new(9.0, 9.0, 8.0, 7.0), v.bbgr());
+    }
+
+    #[test]
+    fn float_swizzle_setters() {
+        let mut v = Vec4n::zero();
+        v.set_wx(Vec2n::new(1.0, 2.0));
+        assert_eq!(Vec4n::new(2.0, 0.0, 0.0, 1.0), v);
+        v.set_gba(Vec3n::new(3.0, 4.0, 5.0));
+        assert_eq!(Vec4n::new(2.0, 3.0, 4.0, 5.0), v);
+        v.set_wzyx(v);
+        assert_eq!(Vec4n::new(5.0, 4.0, 3.0, 2.0), v);
+
+        let mut v = Vec3h::zero();
+        v.set_zy(Vec2h::new(1.0, 2.0));
+        assert_eq!(Vec3h::new(0.0, 2.0, 1.0), v);
+    }
+
+    #[test]
+    fn integer_swizzles() {
+        let mut v = IVec3n::new(1, 2, 3);
+        assert_eq!(IVec2n::new(3, 1), v.zx());
+        assert_eq!(IVec4n::new(1, 2, 3, 3), v.rgbb());
+        v.set_yx(IVec2n::new(7, 8));
+        assert_eq!(IVec3n::new(8, 7, 3), v);
+
+        let v = IVec2h::new(4, 5);
+        assert_eq!(IVec4h::new(5, 4, 5, 4), v.yxyx());
+    }
+}
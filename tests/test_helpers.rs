@@ -1,3 +1,19 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+use straal::*;
+
+//Every component of actual has to be within epsilon of expected, both sides are printed when it fails
+pub fn assert_close_eps<T, S>(expected: T, actual: T, epsilon: S) where T: ApproxEqualExt<S> + fmt::Debug + Copy, S: FloatType<S> + fmt::Debug {
+    assert_abs_diff_eq!(expected, actual, epsilon);
+}
+
+//assert_close_eps with the tolerance most of the f64 tests use
+pub fn assert_close<T>(expected: T, actual: T) where T: ApproxEqualExt<f64> + fmt::Debug + Copy {
+    assert_close_eps(expected, actual, 1e-9);
+}
+
 //use std::time::{Duration, SystemTime};
 //
 //use straal::*;
@@ -0,0 +1,80 @@
+mod test_helpers;
+
+#[cfg(test)]
+pub mod transform_tests {
+    use straal::*;
+
+    use crate::test_helpers::*;
+
+    fn get_test_transform() -> Transformh {
+        Transformh::new(Vec3h::new(1.0, -2.0, 3.0),
+                        Quath::get_quat_from_angle_axis(0.7, Vec3h::new(1.0, 2.0, -1.0).normalized()),
+                        Vec3h::new(2.0, 0.5, 3.0))
+    }
+
+    #[test]
+    fn transform_points_vectors_and_normals() {
+        let t = Transformh::new(Vec3h::new(1.0, 0.0, 0.0),
+                                Quath::get_quat_from_angle_axis(std::f64::consts::FRAC_PI_2, Vec3h::up()),
+                                Vec3h::new(2.0, 1.0, 1.0));
+        let q = t.rotation;
+        assert_close(Vec3h::new(1.0, 0.0, 0.0) + q * Vec3h::new(2.0, 0.0, 0.0), t.transform_point(Vec3h::new(1.0, 0.0, 0.0)));
+        assert_close(q * Vec3h::new(0.0, 0.0, 3.0), t.transform_vector(Vec3h::new(0.0, 0.0, 3.0)));
+
+        //The normal of the plane x + y = 0 stays perpendicular to it after the non-uniform scale
+        let n = t.transform_normal(Vec3h::new(1.0, 1.0, 0.0).normalized());
+        let on_plane = t.transform_vector(Vec3h::new(1.0, -1.0, 0.0));
+        assert!(n.dot(on_plane).abs() < 1e-9);
+        assert!((n.length() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn transform_composition_and_inverse() {
+        let a = Transformh::new(Vec3h::new(0.0, 1.0, 0.0), Quath::get_quat_from_angle_axis(0.3, Vec3h::forward()), Vec3h::all(2.0));
+        let b = get_test_transform();
+        let p = Vec3h::new(0.5, -1.5, 2.0);
+        assert_close(a.transform_point(b.transform_point(p)), (a * b).transform_point(p));
+        assert_close((a.get_mat() * b.get_mat() * Vec4h::from((p, 1.0))).into(), (a * b) * p);
+
+        assert_close(p, a.inverse().transform_point(a.transform_point(p)));
+        assert_eq!(Transformh::identity(), a * a.inverse());
+        let uniform = Transformh::new(b.translation, b.rotation, Vec3h::all(3.0));
+        assert_close(p, (!uniform * uniform) * p);
+    }
+
+    #[test]
+    fn transform_mat4_round_trip() {
+        let t = get_test_transform();
+        let m = Mat4h::from(t);
+        let p = Vec3h::new(0.5, -1.5, 2.0);
+        assert_close(t.transform_point(p), (m * Vec4h::from((p, 1.0))).into());
+        assert_eq!(Some(t), Transformh::try_from_mat4(m));
+
+        let mut built = Mat4h::identity();
+        built.translate(Vec3h::new(4.0, 5.0, 6.0));
+        built.scale_uniformly(Vec3h::new(1.0, -2.0, 3.0));
+        let from_built = Transformh::try_from_mat4(built).unwrap();
+        assert_close(Vec3h::new(4.0, 5.0, 6.0), from_built.translation);
+        assert_eq!(built, Mat4h::from(from_built));
+        assert_close((built * Vec4h::from((p, 1.0))).into(), from_built.transform_point(p));
+
+        //Nothing to decompose, rather than a transform that only keeps the translation
+        let mut flattened = Mat4h::get_translation_mat(Vec3h::new(1.0, 2.0, 3.0));
+        flattened.scale_uniformly(Vec3h::new(1.0, 0.0, 1.0));
+        assert_eq!(None, Transformh::try_from_mat4(flattened));
+        let projection = Mat4h::get_perspective_mat_deg(90.0, 1.0, 1.0, 10.0, Handedness::Left, DepthRange::ZeroToOne);
+        assert_eq!(None, Transformh::try_from_mat4(projection));
+    }
+
+    #[test]
+    fn transform_interpolation() {
+        let a = Transformh::identity();
+        let b = Transformh::new(Vec3h::new(2.0, 0.0, 0.0), Quath::get_quat_from_angle_axis(1.0, Vec3h::up()), Vec3h::all(3.0));
+        let half = a.slerp(&b, 0.5);
+        assert_close(Vec3h::new(1.0, 0.0, 0.0), half.translation);
+        assert_close(Vec3h::all(2.0), half.scale);
+        assert_eq!(Quath::get_quat_from_angle_axis(0.5, Vec3h::up()), half.rotation);
+        assert_eq!(half, a.lerp(&b, 0.5));
+        assert_eq!(b, a.lerp(&b, 1.0));
+    }
+}